@@ -0,0 +1,222 @@
+// Bu dosya (contract.rs), örnek uyumluluk (compliance) kontratını içerir.
+// Kontrat, token kontratının çağırdığı iki fonksiyonu uygular:
+// - 'can_transfer(from, to, amount) -> bool': Transferin kurallara uyup uymadığını döndürür.
+// - 'transferred(from, to, amount)': Başarılı bir transferden sonra token tarafından çağrılır.
+// Basımda (mint) 'from', yakmada (burn) ise 'to' olarak token kontratının kendi adresi gönderilir.
+// Örnek kurallar: alıcının kayıtlı bir ülke kodu olmalı, engellenmiş ülkeler token gönderip alamaz
+// ve her ülke için en fazla token sahibi sayısı (holder cap) uygulanabilir.
+// Token kontratı bu kontratı çağırırken kendisi geri çağrılamayacağı (re-entrancy) için sahip sayıları,
+// 'transferred' bildirimleriyle tutulan bir bakiye yansıması üzerinden hesaplanır. Bu nedenle kontrat,
+// token ilk basımdan önce bağlanmalıdır.
+
+use crate::storage_types::{
+    DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT,
+    INSTANCE_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+// 'read_admin' yardımcı fonksiyonu, kuralları yönetebilen yönetici adresini okur.
+fn read_admin(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+// 'read_token' yardımcı fonksiyonu, bu kontrata bağlı token kontratının adresini okur.
+fn read_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token).unwrap()
+}
+
+// 'read_persistent' yardımcı fonksiyonu, hesap başına kalıcı bir kaydı okur ve (varsa) TTL'sini uzatır.
+fn read_persistent<V: soroban_sdk::TryFromVal<Env, soroban_sdk::Val>>(
+    e: &Env,
+    key: &DataKey,
+) -> Option<V> {
+    let value = e.storage().persistent().get(key);
+    if value.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+    value
+}
+
+// 'write_persistent' yardımcı fonksiyonu, hesap başına kalıcı bir kaydı yazar ve TTL'sini uzatır.
+fn write_persistent<V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(e: &Env, key: &DataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'read_country' yardımcı fonksiyonu, hesabın kayıtlı ülke kodunu (varsa) okur.
+fn read_country(e: &Env, account: &Address) -> Option<u32> {
+    read_persistent(e, &DataKey::Country(account.clone()))
+}
+
+// 'is_blocked' yardımcı fonksiyonu, ülke kodunun engellenmiş olup olmadığını kontrol eder.
+fn is_blocked(e: &Env, country: u32) -> bool {
+    e.storage()
+        .instance()
+        .has(&DataKey::BlockedCountry(country))
+}
+
+// 'read_country_holders' yardımcı fonksiyonu, bir ülkedeki token sahibi sayısını okur.
+fn read_country_holders(e: &Env, country: u32) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::CountryHolders(country))
+        .unwrap_or(0)
+}
+
+// 'add_country_holders' yardımcı fonksiyonu, bir ülkedeki token sahibi sayısını 'delta' kadar değiştirir.
+fn add_country_holders(e: &Env, country: u32, delta: i32) {
+    let holders = read_country_holders(e, country).saturating_add_signed(delta);
+    e.storage()
+        .instance()
+        .set(&DataKey::CountryHolders(country), &holders);
+}
+
+// 'read_balance' yardımcı fonksiyonu, hesabın bakiye yansımasını okur.
+fn read_balance(e: &Env, account: &Address) -> i128 {
+    read_persistent(e, &DataKey::Balance(account.clone())).unwrap_or(0)
+}
+
+// 'write_balance' yardımcı fonksiyonu, hesabın bakiye yansımasını yazar ve ülkesinin sahip sayısını günceller.
+fn write_balance(e: &Env, account: &Address, balance: i128) {
+    let previous = read_balance(e, account);
+    if let Some(country) = read_country(e, account) {
+        if previous == 0 && balance > 0 {
+            add_country_holders(e, country, 1); // Hesap yeni bir sahip oldu.
+        } else if previous > 0 && balance == 0 {
+            add_country_holders(e, country, -1); // Hesap artık sahip değil.
+        }
+    }
+    write_persistent(e, &DataKey::Balance(account.clone()), &balance);
+}
+
+#[contract]
+pub struct Compliance;
+
+#[contractimpl]
+impl Compliance {
+    // '__constructor' fonksiyonu, kontratı dağıtımla aynı işlemde yöneticisi ve bağlı olduğu token adresiyle kurar;
+    // böylece dağıtılmış bir örneği başkasının bizden önce başlatıp kuralları ele geçirmesi mümkün değildir.
+    pub fn __constructor(e: Env, admin: Address, token: Address) {
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::Token, &token);
+    }
+
+    // 'set_country' fonksiyonu, hesabın ülke kodunu (ISO 3166-1 sayısal) ayarlar.
+    // Hesap token sahibiyse, sahip sayısı eski ülkeden yeni ülkeye taşınır. Sadece yönetici çağırabilir.
+    pub fn set_country(e: Env, account: Address, country: u32) {
+        read_admin(&e).require_auth();
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        if read_balance(&e, &account) > 0 {
+            if let Some(previous) = read_country(&e, &account) {
+                add_country_holders(&e, previous, -1);
+            }
+            add_country_holders(&e, country, 1);
+        }
+        write_persistent(&e, &DataKey::Country(account), &country);
+    }
+
+    // 'country' fonksiyonu, hesabın kayıtlı ülke kodunu (varsa) döndürür.
+    pub fn country(e: Env, account: Address) -> Option<u32> {
+        read_country(&e, &account)
+    }
+
+    // 'set_country_blocked' fonksiyonu, bir ülke kodunu engeller veya engelini kaldırır. Sadece yönetici çağırabilir.
+    pub fn set_country_blocked(e: Env, country: u32, blocked: bool) {
+        read_admin(&e).require_auth();
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let key = DataKey::BlockedCountry(country);
+        if blocked {
+            e.storage().instance().set(&key, &true);
+        } else {
+            e.storage().instance().remove(&key);
+        }
+    }
+
+    // 'set_country_holder_cap' fonksiyonu, bir ülkedeki en fazla token sahibi sayısını ayarlar;
+    // 'None' sınırı kaldırır. Sadece yönetici çağırabilir.
+    pub fn set_country_holder_cap(e: Env, country: u32, cap: Option<u32>) {
+        read_admin(&e).require_auth();
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let key = DataKey::CountryHolderCap(country);
+        match cap {
+            Some(cap) => e.storage().instance().set(&key, &cap),
+            None => e.storage().instance().remove(&key),
+        }
+    }
+
+    // 'country_holders' fonksiyonu, bir ülkedeki mevcut token sahibi sayısını döndürür.
+    pub fn country_holders(e: Env, country: u32) -> u32 {
+        read_country_holders(&e, country)
+    }
+
+    // 'can_transfer' fonksiyonu, 'from' adresinden 'to' adresine 'amount' kadar transferin kurallara uyup uymadığını döndürür.
+    pub fn can_transfer(e: Env, from: Address, to: Address, amount: i128) -> bool {
+        let token = read_token(&e);
+
+        // Engellenmiş bir ülkedeki hesap token gönderemez (basımda gönderen token kontratıdır).
+        if from != token {
+            if let Some(country) = read_country(&e, &from) {
+                if is_blocked(&e, country) {
+                    return false;
+                }
+            }
+        }
+
+        // Yakma işleminde alıcı yoktur.
+        if to == token {
+            return true;
+        }
+
+        // Alıcının kayıtlı ve engellenmemiş bir ülkesi olmalıdır.
+        let Some(country) = read_country(&e, &to) else {
+            return false;
+        };
+        if is_blocked(&e, country) {
+            return false;
+        }
+
+        // Alıcı yeni bir sahip olacaksa ülkesinin sahip sınırı kontrol edilir.
+        if amount > 0 && read_balance(&e, &to) == 0 {
+            let cap: Option<u32> = e
+                .storage()
+                .instance()
+                .get(&DataKey::CountryHolderCap(country));
+            if let Some(cap) = cap {
+                if read_country_holders(&e, country) >= cap {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    // 'transferred' fonksiyonu, token kontratının başarılı bir transferden sonra gönderdiği bildirimdir.
+    // Bakiye yansımasını ve ülke sahip sayılarını günceller. Yalnızca bağlı token kontratı çağırabilir.
+    pub fn transferred(e: Env, from: Address, to: Address, amount: i128) {
+        let token = read_token(&e);
+        token.require_auth();
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        if from != token {
+            write_balance(&e, &from, read_balance(&e, &from) - amount);
+        }
+        if to != token {
+            write_balance(&e, &to, read_balance(&e, &to) + amount);
+        }
+    }
+}
@@ -0,0 +1,13 @@
+// Bu dosya (lib.rs), 'soroban-compliance-contract' adlı Rust kütüphanesinin (crate) ana dosyasıdır.
+// Bu kütüphane, 'soroban-token-contract' için örnek (referans) bir uyumluluk (compliance) kural motoru içerir.
+// Token kontratı, kayıtlı bir uyumluluk kontratının 'can_transfer' fonksiyonunu her transfer, basım (mint)
+// ve yakma (burn) öncesinde çağırır ve başarılı işlemlerden sonra 'transferred' ile bildirim gönderir.
+// Bu sayede düzenleyici kurallar, token kontratını yükseltmeden ayrı bir kontratta değiştirilebilir.
+
+#![no_std] // Soroban akıllı kontratları WebAssembly olarak derlendiği için standart kütüphane kullanılmaz.
+
+mod contract;      // Uyumluluk kontratının kendisini ve kurallarını içerir.
+mod storage_types; // Kontratın depolama anahtarlarını tanımlar.
+mod test;          // Kontratın birim testlerini içerir.
+
+pub use crate::contract::{Compliance, ComplianceClient}; // Kontratı ve istemcisini dış kodlar ve testler için erişilebilir hale getirir.
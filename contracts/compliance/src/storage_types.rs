@@ -0,0 +1,27 @@
+// Bu dosya (storage_types.rs), 'soroban-compliance-contract' kütüphanesinin depolama anahtarlarını
+// ve TTL (Time-To-Live) sabitlerini tanımlar. Sabitler, token kontratındakilerle aynı değerleri kullanır.
+
+use soroban_sdk::{contracttype, Address};
+
+// 'DAY_IN_LEDGERS': Yaklaşık olarak bir güne denk gelen defter sayısı (5 saniyelik defterlerle 17280).
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+
+// Kontrat örneği (instance) depolaması için TTL artırma miktarı ve eşiği.
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// Hesap başına tutulan kalıcı kayıtlar (ülke, bakiye yansıması) için TTL artırma miktarı ve eşiği.
+pub(crate) const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {               // Uyumluluk kontratının depolamasında kullanılan anahtarlar.
+    Admin,                       // Kuralları yönetebilen yönetici adresi.
+    Token,                       // Bu kontrata bağlı olan token kontratının adresi.
+    Country(Address),            // Bir hesabın ülke kodu (ISO 3166-1 sayısal).
+    BlockedCountry(u32),         // Bir ülke kodunun engellenmiş olup olmadığı.
+    CountryHolderCap(u32),       // Bir ülkedeki en fazla token sahibi sayısı.
+    CountryHolders(u32),         // Bir ülkedeki mevcut token sahibi sayısı.
+    Balance(Address),            // Token bakiyelerinin 'transferred' bildirimleriyle tutulan yansıması.
+}
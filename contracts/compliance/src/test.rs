@@ -0,0 +1,65 @@
+// Bu dosya (test.rs), örnek uyumluluk (compliance) kontratının birim testlerini içerir.
+// Token kontratıyla birlikte aynı 'Env' içinde çalışan testler, token kontratının test dosyasındadır.
+
+#![cfg(test)]
+extern crate std;
+
+use crate::{Compliance, ComplianceClient};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+// 'create_compliance' yardımcı fonksiyonu, testler için yeni bir uyumluluk kontratı oluşturur ve başlatır.
+fn create_compliance<'a>(e: &Env, admin: &Address, token: &Address) -> ComplianceClient<'a> {
+    ComplianceClient::new(e, &e.register(Compliance, (admin.clone(), token.clone())))
+}
+
+#[test] // Ülke kodu ve engellenmiş ülke kurallarını test eder.
+fn test_country_rules() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let compliance = create_compliance(&e, &admin, &token);
+
+    // Ülkesi kayıtlı olmayan hesap token alamaz.
+    assert!(!compliance.can_transfer(&token, &user1, &100));
+    compliance.set_country(&user1, &792);
+    assert_eq!(compliance.country(&user1), Some(792));
+    assert!(compliance.can_transfer(&token, &user1, &100));
+
+    // Engellenmiş ülkedeki hesap ne alabilir ne gönderebilir; yakma her zaman serbesttir.
+    compliance.set_country(&user2, &408);
+    compliance.set_country_blocked(&408, &true);
+    assert!(!compliance.can_transfer(&user1, &user2, &100));
+    assert!(!compliance.can_transfer(&user2, &user1, &100));
+    compliance.set_country_blocked(&408, &false);
+    assert!(compliance.can_transfer(&user2, &user1, &100));
+    assert!(compliance.can_transfer(&user1, &token, &100));
+}
+
+#[test] // Ülke başına token sahibi sınırının 'transferred' bildirimleriyle takip edilmesini test eder.
+fn test_country_holder_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let compliance = create_compliance(&e, &admin, &token);
+
+    compliance.set_country(&user1, &792);
+    compliance.set_country(&user2, &792);
+    compliance.set_country_holder_cap(&792, &Some(1));
+
+    compliance.transferred(&token, &user1, &100); // 'user1'e basım yapıldı.
+    assert_eq!(compliance.country_holders(&792), 1);
+    assert!(!compliance.can_transfer(&user1, &user2, &50)); // İkinci sahip sınırı aşar.
+    assert!(compliance.can_transfer(&token, &user1, &50)); // Mevcut sahip token almaya devam edebilir.
+
+    compliance.transferred(&user1, &token, &100); // 'user1' tüm bakiyesini yaktı.
+    assert_eq!(compliance.country_holders(&792), 0);
+    assert!(compliance.can_transfer(&token, &user2, &50));
+}
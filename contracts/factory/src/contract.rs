@@ -0,0 +1,127 @@
+// Bu dosya (contract.rs), token fabrikası (factory) kontratını içerir.
+// Fabrika, saklanan Wasm kod özetiyle (hash) yeni 'Token' örnekleri dağıtır. Her örnek, çağıranın verdiği
+// tuz (salt) ile fabrikanın adresinden deterministik olarak türetilen bir adrese dağıtılır; bu adres
+// 'token_address' ile önceden hesaplanabilir. Token'ın kurucusu ('__constructor') dağıtımla aynı işlemde
+// çalıştığından yönetici ve meta veriler atomik olarak ayarlanır ve örnek başkası tarafından ele geçirilemez.
+// Dağıtılan her token, sıra numarasıyla bir kayda eklenir ve 'tokens' ile sayfalanarak listelenebilir.
+
+use crate::storage_types::{
+    DataKey, TokenParams, INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD, TOKEN_BUMP_AMOUNT,
+    TOKEN_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+// 'read_admin' yardımcı fonksiyonu, fabrikanın yönetici adresini okur.
+fn read_admin(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+// 'read_wasm_hash' yardımcı fonksiyonu, dağıtılacak token'ların Wasm kod özetini okur.
+fn read_wasm_hash(e: &Env) -> BytesN<32> {
+    e.storage().instance().get(&DataKey::WasmHash).unwrap()
+}
+
+// 'read_token_count' yardımcı fonksiyonu, şimdiye kadar dağıtılan token sayısını okur.
+fn read_token_count(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::TokenCount).unwrap_or(0)
+}
+
+// 'read_token' yardımcı fonksiyonu, belirli bir sıra numarasındaki token'ın adresini okur ve kaydın TTL'sini uzatır.
+fn read_token(e: &Env, index: u32) -> Address {
+    let key = DataKey::Token(index);
+    let token = e.storage().persistent().get(&key).unwrap();
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, TOKEN_LIFETIME_THRESHOLD, TOKEN_BUMP_AMOUNT);
+    token
+}
+
+// 'add_token' yardımcı fonksiyonu, yeni dağıtılan token'ı kaydın sonuna ekler ve sıra numarasını döndürür.
+fn add_token(e: &Env, token: &Address) -> u32 {
+    let index = read_token_count(e);
+    let key = DataKey::Token(index);
+    e.storage().persistent().set(&key, token);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, TOKEN_LIFETIME_THRESHOLD, TOKEN_BUMP_AMOUNT);
+    e.storage().instance().set(&DataKey::TokenCount, &(index + 1));
+    index
+}
+
+#[contract]
+pub struct Factory;
+
+#[contractimpl]
+impl Factory {
+    // '__constructor' fonksiyonu, fabrikayı dağıtımla aynı işlemde yöneticisi ve token Wasm kod özetiyle kurar.
+    pub fn __constructor(e: Env, admin: Address, wasm_hash: BytesN<32>) {
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+    }
+
+    // 'set_wasm_hash' fonksiyonu, bundan sonra dağıtılacak token'ların Wasm kod özetini değiştirir.
+    // Daha önce dağıtılmış token'lar etkilenmez. Sadece yönetici çağırabilir.
+    pub fn set_wasm_hash(e: Env, wasm_hash: BytesN<32>) {
+        read_admin(&e).require_auth();
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        e.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+    }
+
+    // 'wasm_hash' fonksiyonu, dağıtılacak token'ların Wasm kod özetini döndürür.
+    pub fn wasm_hash(e: Env) -> BytesN<32> {
+        read_wasm_hash(&e)
+    }
+
+    // 'deploy' fonksiyonu, 'salt' tuzuyla yeni bir token örneği dağıtır ve kurucusunu 'params' içindeki yönetici
+    // ve meta verilerle aynı işlemde çalıştırır. 'params.underlying' verilirse token, o SEP-41 varlığını 1:1 saran
+    // sarmalayıcı modunda kurulur. Token kayda eklenir ve adresi döndürülür. Aynı tuz ikinci kez kullanılamaz.
+    // Sadece fabrikanın yöneticisi çağırabilir.
+    pub fn deploy(e: Env, salt: BytesN<32>, params: TokenParams) -> Address {
+        read_admin(&e).require_auth();
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let token = e.deployer().with_current_contract(salt.clone()).deploy_v2(
+            read_wasm_hash(&e),
+            (
+                params.admin.clone(),
+                params.decimal,
+                params.name.clone(),
+                params.symbol.clone(),
+                params.mode,
+                params.underlying,
+            ),
+        );
+        let index = add_token(&e, &token);
+
+        // Dağıtılan token'ın adresini, sıra numarasını, yöneticisini ve meta verilerini içeren bir 'deploy' olayı yayınla.
+        e.events()
+            .publish(("deploy", token.clone(), index), (salt, params.admin, params.name, params.symbol));
+        token
+    }
+
+    // 'token_address' fonksiyonu, verilen tuzla dağıtılan (veya dağıtılacak) token'ın adresini döndürür.
+    pub fn token_address(e: Env, salt: BytesN<32>) -> Address {
+        e.deployer().with_current_contract(salt).deployed_address()
+    }
+
+    // 'token_count' fonksiyonu, fabrikanın şimdiye kadar dağıttığı token sayısını döndürür.
+    pub fn token_count(e: Env) -> u32 {
+        read_token_count(&e)
+    }
+
+    // 'tokens' fonksiyonu, dağıtılan token'ları dağıtım sırasıyla, 'start' sıra numarasından başlayarak
+    // en fazla 'limit' adet döndürür.
+    pub fn tokens(e: Env, start: u32, limit: u32) -> Vec<Address> {
+        let end = read_token_count(&e).min(start.saturating_add(limit));
+        let mut tokens = Vec::new(&e);
+        for index in start..end {
+            tokens.push_back(read_token(&e, index));
+        }
+        tokens
+    }
+}
@@ -0,0 +1,14 @@
+// Bu dosya (lib.rs), 'soroban-token-factory' adlı Rust kütüphanesinin (crate) ana dosyasıdır.
+// Bu kütüphane, 'soroban-token-contract' örneklerini (her fon için bir token gibi) tek bir çağrıyla dağıtan
+// bir fabrika (factory) kontratı içerir. Fabrika, token Wasm kod özetini (hash) saklar, yeni örnekleri
+// deterministik tuzlarla (salt) dağıtır, kurucularını aynı işlemde çalıştırır ve dağıtılan token'ların kaydını tutar.
+
+#![no_std] // Soroban akıllı kontratları WebAssembly olarak derlendiği için standart kütüphane kullanılmaz.
+
+mod contract;      // Fabrika kontratının kendisini içerir.
+mod storage_types; // Kontratın depolama anahtarlarını ve token kurucusuna geçirilen türleri tanımlar.
+#[cfg(feature = "token-wasm")]
+mod test;          // Kontratın entegrasyon testlerini içerir; derlenmiş token Wasm dosyası gerektiğinden 'token-wasm' özelliğiyle açılır.
+
+pub use crate::contract::{Factory, FactoryClient}; // Kontratı ve istemcisini dış kodlar ve testler için erişilebilir hale getirir.
+pub use crate::storage_types::{AccessMode, TokenParams}; // Token kurucusuna geçirilen değerleri dış kodlar için erişilebilir hale getirir.
@@ -0,0 +1,43 @@
+// Bu dosya (storage_types.rs), 'soroban-token-factory' kütüphanesinin depolama anahtarlarını,
+// TTL (Time-To-Live) sabitlerini ve token kurucusuna geçirilen türleri tanımlar.
+// Sabitler, token kontratındakilerle aynı değerleri kullanır.
+
+use soroban_sdk::{contracttype, Address, String};
+
+// 'DAY_IN_LEDGERS': Yaklaşık olarak bir güne denk gelen defter sayısı (5 saniyelik defterlerle 17280).
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+
+// Kontrat örneği (instance) depolaması için TTL artırma miktarı ve eşiği.
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// Dağıtılan token kayıtları için TTL artırma miktarı ve eşiği.
+pub(crate) const TOKEN_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const TOKEN_LIFETIME_THRESHOLD: u32 = TOKEN_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AccessMode {            // Token kontratındaki 'AccessMode' ile aynı kodlanan erişim modu.
+    Open,                        // Herkes token tutabilir ve alabilir.
+    Allowlist,                   // Yalnızca izin listesindeki hesaplar token tutabilir ve alabilir.
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TokenParams {         // Dağıtılan token'ın kurucusuna ('__constructor') geçirilen değerler.
+    pub admin: Address,          // Token'ın yöneticisi.
+    pub decimal: u32,            // Token'ın ondalık basamak sayısı.
+    pub name: String,            // Token'ın adı.
+    pub symbol: String,          // Token'ın sembolü.
+    pub mode: AccessMode,        // Token'ın erişim modu.
+    pub underlying: Option<Address>, // Sarmalayıcı modunda 1:1 sarılacak SEP-41 token'ın adresi; normal token için 'None'.
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {               // Fabrika kontratının depolamasında kullanılan anahtarlar.
+    Admin,                       // Token dağıtabilen ve Wasm kod özetini değiştirebilen yönetici adresi.
+    WasmHash,                    // Dağıtılan token'ların Wasm kod özeti (hash).
+    TokenCount,                  // Şimdiye kadar dağıtılan token sayısı.
+    Token(u32),                  // Belirli bir sıra numarasıyla dağıtılan token'ın adresi.
+}
@@ -0,0 +1,114 @@
+// Bu dosya (test.rs), token fabrikası kontratının entegrasyon testlerini içerir.
+// Testler, token kontratının derlenmiş Wasm dosyasını kullanır; bu yüzden yalnızca 'token-wasm' özelliğiyle derlenir.
+// 'make test' önce token kontratını derler, sonra testleri bu özellikle çalıştırır.
+
+#![cfg(test)]
+extern crate std;
+
+use crate::{AccessMode, Factory, FactoryClient, TokenParams};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Address, BytesN, Env, IntoVal, String,
+};
+
+// 'token' modülü, derlenmiş token kontratının arayüzünü (istemci ve türler) Wasm dosyasından içeri aktarır.
+mod token {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/soroban_token_contract.wasm"
+    );
+}
+
+// 'create_factory' yardımcı fonksiyonu, token Wasm kodunu yükler ve bu kod özetiyle yeni bir fabrika kontratı oluşturur.
+fn create_factory<'a>(e: &Env, admin: &Address) -> FactoryClient<'a> {
+    let wasm_hash = e.deployer().upload_contract_wasm(token::WASM);
+    FactoryClient::new(e, &e.register(Factory, (admin.clone(), wasm_hash)))
+}
+
+// 'fund_params' yardımcı fonksiyonu, testlerde dağıtılan bir fon token'ı için kurucu değerlerini oluşturur.
+fn fund_params(e: &Env, admin: &Address, symbol: &str, mode: AccessMode) -> TokenParams {
+    TokenParams {
+        admin: admin.clone(),
+        decimal: 7,
+        name: String::from_str(e, "Fund"),
+        symbol: String::from_str(e, symbol),
+        mode,
+        underlying: None,
+    }
+}
+
+#[test] // Aynı 'Env' içinde birden fazla token dağıtmayı, kaydı, deterministik adresleri ve dağıtım olayını test eder.
+fn test_deploy_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let fund_admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let factory = create_factory(&e, &admin);
+
+    // Adres, dağıtımdan önce tuzdan deterministik olarak hesaplanabilir.
+    let salts = [[1u8; 32], [2u8; 32], [3u8; 32]];
+    let expected = factory.token_address(&BytesN::from_array(&e, &salts[0]));
+
+    let mut deployed = std::vec::Vec::new();
+    for (i, salt) in salts.iter().enumerate() {
+        let symbol = std::format!("FUND{}", i + 1);
+        let token = factory.deploy(
+            &BytesN::from_array(&e, salt),
+            &fund_params(&e, &fund_admin, &symbol, AccessMode::Open),
+        );
+        let event = e.events().all().last().unwrap();
+        assert_eq!(event.0, factory.address);
+        assert_eq!(event.1, ("deploy", token.clone(), i as u32).into_val(&e));
+        deployed.push(token);
+    }
+    assert_eq!(deployed[0], expected);
+    assert_eq!(factory.token_count(), 3);
+
+    // Kayıt sayfalanarak okunabilir.
+    let page = factory.tokens(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap(), deployed[0]);
+    assert_eq!(page.get(1).unwrap(), deployed[1]);
+    let page = factory.tokens(&2, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), deployed[2]);
+    assert_eq!(factory.tokens(&5, &10).len(), 0);
+
+    // Dağıtılan token'lar kurucuda atomik olarak kurulmuştur ve bağımsız çalışır.
+    let fund1 = token::Client::new(&e, &deployed[0]);
+    let fund2 = token::Client::new(&e, &deployed[1]);
+    assert_eq!(fund1.symbol(), String::from_str(&e, "FUND1"));
+    assert_eq!(fund2.symbol(), String::from_str(&e, "FUND2"));
+    assert_eq!(fund1.admin(), Some(fund_admin.clone()));
+    fund1.mint(&user, &1000);
+    assert_eq!(fund1.balance(&user), 1000);
+    assert_eq!(fund2.balance(&user), 0);
+
+    // Aynı tuz ikinci kez kullanılamaz.
+    assert!(factory
+        .try_deploy(
+            &BytesN::from_array(&e, &salts[0]),
+            &fund_params(&e, &fund_admin, "DUP", AccessMode::Open),
+        )
+        .is_err());
+}
+
+#[test] // Dağıtımın ve Wasm kod özeti değişikliğinin fabrika yöneticisinin imzasını gerektirdiğini test eder.
+fn test_admin_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let factory = create_factory(&e, &admin);
+
+    factory.deploy(
+        &BytesN::from_array(&e, &[7u8; 32]),
+        &fund_params(&e, &admin, "FUND", AccessMode::Allowlist),
+    );
+    assert_eq!(e.auths()[0].0, admin);
+
+    let hash = factory.wasm_hash();
+    factory.set_wasm_hash(&hash);
+    assert_eq!(e.auths()[0].0, admin);
+}
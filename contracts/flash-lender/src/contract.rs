@@ -0,0 +1,70 @@
+// Bu dosya (contract.rs), örnek anlık kredi (flash loan) kontratını içerir.
+// Kontrat, token kontratının yöneticisi tarafından 'set_flash_lender' ile kaydedilir ve token'ın şu iki
+// fonksiyonunu çağırır:
+// - 'flash_mint(receiver, amount) -> i128': Krediyi alıcıya basar ve ücreti döndürür.
+// - 'flash_settle(receiver)': Alıcının bu kontrata verdiği harcama izniyle 'amount + fee' tutarını geri çeker.
+// Alıcı kontrat 'on_flash_loan(initiator, amount, fee, data) -> bool' fonksiyonunu uygular. Geri ödeme için
+// krediden önce bu kontrata en az 'amount + fee' kadar harcama izni ('approve') vermiş olmalıdır.
+
+use crate::storage_types::{DataKey, INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{contract, contractclient, contractimpl, Address, Bytes, Env};
+
+// 'FlashTokenInterface', bu kontratın token kontratında çağırdığı anlık kredi fonksiyonlarıdır.
+#[allow(dead_code)] // Arayüz yalnızca istemci üretmek için kullanılır; hiçbir tür tarafından uygulanmaz.
+#[contractclient(name = "FlashTokenClient")]
+pub trait FlashTokenInterface {
+    fn flash_mint(env: Env, receiver: Address, amount: i128) -> i128;
+    fn flash_settle(env: Env, receiver: Address);
+}
+
+// 'FlashLoanReceiverInterface', anlık kredi alan kontratların uygulaması gereken geri çağrı arayüzüdür.
+// Alıcı krediyi kabul ediyorsa 'true' döndürmelidir; 'false' döndürmek tüm çağrıyı geri alır.
+#[allow(dead_code)] // Arayüz yalnızca istemci üretmek için kullanılır; hiçbir tür tarafından uygulanmaz.
+#[contractclient(name = "FlashLoanReceiverClient")]
+pub trait FlashLoanReceiverInterface {
+    fn on_flash_loan(env: Env, initiator: Address, amount: i128, fee: i128, data: Bytes) -> bool;
+}
+
+// 'read_token' yardımcı fonksiyonu, kredi verilen token kontratının adresini okur.
+fn read_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token).unwrap()
+}
+
+#[contract]
+pub struct FlashLender;
+
+#[contractimpl]
+impl FlashLender {
+    // '__constructor' fonksiyonu, kontrat dağıtılırken kredi verilecek token kontratını kaydeder.
+    pub fn __constructor(e: Env, token: Address) {
+        e.storage().instance().set(&DataKey::Token, &token);
+    }
+
+    // 'flash_loan' fonksiyonu, 'receiver' kontratına 'amount' kadar anlık kredi verir: token krediyi basar,
+    // 'receiver.on_flash_loan' çağrılır ve ardından token 'amount + fee' tutarını alıcının bu kontrata verdiği
+    // harcama izniyle geri çeker. Alıcı krediyi reddederse veya geri ödeyemezse tüm çağrı geri alınır.
+    // 'initiator' işlemi imzalamalıdır.
+    pub fn flash_loan(e: Env, initiator: Address, receiver: Address, amount: i128, data: Bytes) {
+        initiator.require_auth(); // Krediyi başlatanın işlemi imzalamasını zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let token = FlashTokenClient::new(&e, &read_token(&e));
+        let fee = token.flash_mint(&receiver, &amount);
+
+        // Token bu noktada çağrı yığınında olmadığından alıcı ödünç aldığı token'ları kullanabilir.
+        if !FlashLoanReceiverClient::new(&e, &receiver).on_flash_loan(&initiator, &amount, &fee, &data) {
+            panic!("flash loan rejected by receiver");
+        }
+
+        token.flash_settle(&receiver);
+    }
+
+    // 'token' fonksiyonu, kredi verilen token kontratının adresini döndürür.
+    pub fn token(e: Env) -> Address {
+        read_token(&e)
+    }
+}
@@ -0,0 +1,14 @@
+// Bu dosya (lib.rs), 'soroban-flash-lender' adlı Rust kütüphanesinin (crate) ana dosyasıdır.
+// Bu kütüphane, 'soroban-token-contract' için örnek (referans) bir anlık kredi (flash loan) kontratı içerir.
+// Soroban bir kontratın çağrı yığınındayken yeniden çağrılmasına (re-entrancy) izin vermez; token kontratı alıcıyı
+// kendisi geri çağırsaydı alıcı ödünç aldığı token'ları kullanamazdı. Bu yüzden krediyi ayrı bir kontrat yönetir:
+// token'dan krediyi basmasını ister ('flash_mint'), alıcıyı geri çağırır ve ardından token'dan geri ödemeyi
+// çekmesini ister ('flash_settle'). Alıcı geri çağrı sırasında token kontratını serbestçe çağırabilir.
+// Token kontratıyla birlikte aynı 'Env' içinde çalışan testler, token kontratının test dosyasındadır.
+
+#![no_std] // Soroban akıllı kontratları WebAssembly olarak derlendiği için standart kütüphane kullanılmaz.
+
+mod contract;      // Anlık kredi kontratının kendisini ve token ile alıcı arayüzlerini içerir.
+mod storage_types; // Kontratın depolama anahtarlarını tanımlar.
+
+pub use crate::contract::{FlashLender, FlashLenderClient}; // Kontratı ve istemcisini dış kodlar ve testler için erişilebilir hale getirir.
@@ -0,0 +1,17 @@
+// Bu dosya (storage_types.rs), 'soroban-flash-lender' kütüphanesinin depolama anahtarlarını
+// ve TTL (Time-To-Live) sabitlerini tanımlar. Sabitler, token kontratındakilerle aynı değerleri kullanır.
+
+use soroban_sdk::contracttype;
+
+// 'DAY_IN_LEDGERS': Yaklaşık olarak bir güne denk gelen defter sayısı (5 saniyelik defterlerle 17280).
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+
+// Kontrat örneği (instance) depolaması için TTL artırma miktarı ve eşiği.
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {               // Anlık kredi kontratının depolamasında kullanılan anahtarlar.
+    Token,                       // Kredi verilen token kontratının adresi.
+}
@@ -9,19 +9,10 @@ use soroban_sdk::{Address, Env}; // soroban_sdk kütüphanesinden 'Address' ve '
                                  // 'Env': Mevcut Soroban çalışma zamanı ortamına erişim sağlayan yapı (environment).
                                  //          Kontrat depolamasına, güncel defter bilgilerine vb. erişmek için kullanılır.
 
-use crate::storage_types::DataKey; // Mevcut kütüphanenin (crate) 'storage_types' modülünden 'DataKey' enum'ını içeri aktarır.
-                                   // 'DataKey', kontrat depolamasında verileri organize etmek için kullanılan anahtarları tanımlar.
-
-// 'has_administrator' fonksiyonu, kontrat depolamasında bir yönetici adresinin kayıtlı olup olmadığını kontrol eder.
-pub fn has_administrator(e: &Env) -> bool { // 'e' parametresi, Soroban çalışma zamanı ortamına bir referanstır.
-                                            // Fonksiyon, bir boolean (true/false) değer döndürür.
-    let key = DataKey::Admin;               // Depolama anahtarı olarak 'DataKey' enum'ının 'Admin' varyantını kullanır.
-                                            // Bu, yönetici adresinin depolamada bu anahtar altında saklandığını gösterir.
-    e.storage()                             // 'Env' üzerinden kontratın depolama alanına erişir.
-        .instance()                         // 'instance' depolama alanını seçer. Bu, kontratın ömrü boyunca devam eden veriler için kullanılır.
-        .has(&key)                          // Belirtilen 'key' ile depolamada bir değer olup olmadığını kontrol eder.
-                                            // Varsa 'true', yoksa 'false' döndürür.
-}
+use crate::council::read_admin_council; // Yönetici konseyinin ayarlı olup olmadığını kontrol etmek için kullanılır.
+use crate::storage_types::{DataKey, Role}; // Mevcut kütüphanenin (crate) 'storage_types' modülünden 'DataKey' ve 'Role' enum'larını içeri aktarır.
+                                           // 'DataKey', kontrat depolamasında verileri organize etmek için kullanılan anahtarları tanımlar.
+                                           // 'Role', yöneticinin devredebileceği yetkileri (örneğin KYC operatörü) tanımlar.
 
 // 'read_administrator' fonksiyonu, kontrat depolamasından kayıtlı yönetici adresini okur.
 pub fn read_administrator(e: &Env) -> Address { // 'e' parametresi, Soroban çalışma zamanı ortamına bir referanstır.
@@ -31,10 +22,27 @@ pub fn read_administrator(e: &Env) -> Address { // 'e' parametresi, Soroban çal
         .instance()                            // 'instance' depolama alanını kullanır.
         .get(&key)                             // Belirtilen 'key' ile depolamadan değeri alır.
                                                // Bu, bir `Option<Address>` döndürür.
-        .unwrap()                              // `Option` içerisindeki değeri çıkarır. Eğer değer `None` ise (yani anahtar bulunamazsa)
-                                               // program panikler. Bu kullanım, yönetici adresinin her zaman var olması
-                                               // gerektiği varsayımına dayanır (muhtemelen kontrat başlatıldığında ayarlanmıştır).
-                                               // Daha güvenli bir yaklaşım, `expect()` veya `match` ile `None` durumunu ele almak olabilir.
+        .unwrap_or_else(|| panic!("admin has been renounced; the token is immutable"))
+                                               // `Option` içerisindeki değeri çıkarır. Değer `None` ise (yönetici 'renounce_admin' ile
+                                               // bırakılmışsa) yönetici yetkisi gerektiren tüm işlemler açık bir hata mesajıyla panikler.
+}
+
+// 'try_read_administrator' fonksiyonu, kayıtlı yönetici adresini (varsa) okur.
+// Yönetici bırakılmışsa 'None' döner; yöneticinin olmaması bir hata olmayan yerlerde kullanılır.
+pub fn try_read_administrator(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Admin)
+}
+
+// 'remove_administrator' fonksiyonu, yönetici adresini kalıcı olarak siler ve yöneticinin bırakıldığını işaretler.
+// Bu işaret, verilmiş rollerin de artık kullanılamayacağını belirtir.
+pub fn remove_administrator(e: &Env) {
+    e.storage().instance().remove(&DataKey::Admin);
+    e.storage().instance().set(&DataKey::AdminRenounced, &true);
+}
+
+// 'is_admin_renounced' fonksiyonu, yöneticinin 'renounce_admin' ile bırakılıp bırakılmadığını döndürür.
+pub fn is_admin_renounced(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::AdminRenounced)
 }
 
 // 'write_administrator' fonksiyonu, kontrat depolamasına yeni bir yönetici adresi yazar (veya mevcut olanı günceller).
@@ -44,4 +52,58 @@ pub fn write_administrator(e: &Env, id: &Address) { // 'e': Soroban çalışma z
     e.storage()                                     // Kontratın depolama alanına erişir.
         .instance()                                 // 'instance' depolama alanını kullanır.
         .set(&key, id);                             // Belirtilen 'key' altına verilen 'id' (yönetici adresi) değerini yazar/günceller.
-}
\ No newline at end of file
+}
+
+// 'read_treasury' fonksiyonu, kayıtlı hazine (treasury) adresini (varsa) okur.
+pub fn read_treasury(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Treasury)
+}
+
+// 'write_treasury' fonksiyonu, hazine (treasury) adresini depolamaya yazar (veya mevcut olanı günceller).
+pub fn write_treasury(e: &Env, id: &Address) {
+    e.storage().instance().set(&DataKey::Treasury, id);
+}
+
+// 'has_role' fonksiyonu, 'account' adresinin verilen role sahip olup olmadığını kontrol eder.
+// Roller yönetici tarafından verildiğinden, yönetici bırakıldıktan sonra hiçbir rol geçerli değildir.
+pub fn has_role(e: &Env, role: Role, account: &Address) -> bool {
+    !is_admin_renounced(e)
+        && e.storage()
+            .instance()
+            .has(&DataKey::Role(role, account.clone()))
+}
+
+// 'write_role' fonksiyonu, 'account' adresine verilen rolü atar ('granted' true) veya geri alır ('granted' false).
+pub fn write_role(e: &Env, role: Role, account: &Address, granted: bool) {
+    let key = DataKey::Role(role, account.clone());
+    if granted {
+        e.storage().instance().set(&key, &true);
+    } else {
+        e.storage().instance().remove(&key);
+    }
+}
+
+// 'require_admin_or_role' fonksiyonu, 'caller' adresinin yönetici veya verilen role sahip olduğunu doğrular
+// ve işlemi imzalamasını zorunlu kılar. İkisi de değilse program panikler.
+// Yönetici bırakıldıysa rol gerektiren işlemler de kapalıdır.
+pub fn require_admin_or_role(e: &Env, role: Role, caller: &Address) {
+    if is_admin_renounced(e) {
+        panic!("admin has been renounced");
+    }
+    if try_read_administrator(e).as_ref() != Some(caller) && !has_role(e, role, caller) {
+        panic!("caller is not authorized for this role");
+    }
+    caller.require_auth();
+}
+
+// 'require_admin' fonksiyonu, yöneticinin işlemi imzalamasını zorunlu kılar ve yönetici adresini döndürür.
+// Yönetici konseyi ayarlıysa tek yöneticili giriş noktaları kapalıdır ve program panikler; bu durumda işlemler
+// 'council_execute' veya konsey önerileri üzerinden yürütülmelidir.
+pub fn require_admin(e: &Env) -> Address {
+    if read_admin_council(e).is_some() {
+        panic!("admin is a council; use council_execute or a proposal");
+    }
+    let admin = read_administrator(e);
+    admin.require_auth();
+    admin
+}
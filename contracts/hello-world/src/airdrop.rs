@@ -0,0 +1,100 @@
+// Bu dosya (airdrop.rs), 'soroban-token-contract' kütüphanesinin 'airdrop' modülünü oluşturur.
+// Bu modül, Merkle ağacı tabanlı airdrop'ları yönetir. Yönetici binlerce adrese tek tek basım yapmak yerine yalnızca
+// bir Merkle kökü ('root') ve toplam miktarı kaydeder; her hak sahibi kendi yaprağının SHA-256 Merkle kanıtını
+// sunarak payını talep eder. Yaprak, 'sha256(index || account (XDR) || amount)' olarak hesaplanır; ara düğümler
+// 'sha256(sol || sağ)' şeklindedir ve her seviyede düğümün solda mı sağda mı olduğu 'index'in ilgili biti ile belirlenir.
+// Talep edilen yapraklar, 'index' ile adreslenen 128 bitlik kelimelerden oluşan bir bit haritasında (bitmap) işaretlenir.
+// Airdrop oluşturulurken toplam miktar hazineden kontratın emanetine aktarılır; talepler ve iadeler bu emanetten
+// ödenir, yeni token basılmaz. Emanetteki miktar, kontrat bakiyesindeki ödül emanetinden ayrı olarak
+// 'balance' modülündeki emanet toplamında izlenir.
+
+use crate::storage_types::{
+    Airdrop, DataKey, MerkleProof, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+// 'read_airdrop_count' fonksiyonu, şimdiye kadar oluşturulan airdrop sayısını okur. Bir sonraki airdrop bu kimliği alır.
+pub fn read_airdrop_count(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::AirdropCount).unwrap_or(0)
+}
+
+// 'next_airdrop_id' fonksiyonu, yeni bir airdrop için benzersiz bir kimlik üretir ve sayacı bir artırır.
+pub fn next_airdrop_id(e: &Env) -> u32 {
+    let id = read_airdrop_count(e);
+    e.storage().instance().set(&DataKey::AirdropCount, &(id + 1));
+    id
+}
+
+// 'read_airdrop' fonksiyonu, verilen kimliğe sahip airdrop kaydını okur; bulunamazsa program panikler.
+pub fn read_airdrop(e: &Env, id: u32) -> Airdrop {
+    let key = DataKey::Airdrop(id);
+    let airdrop = e
+        .storage()
+        .persistent()
+        .get::<DataKey, Airdrop>(&key)
+        .unwrap_or_else(|| panic!("airdrop not found"));
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    airdrop
+}
+
+// 'write_airdrop' fonksiyonu, airdrop kaydını kalıcı depolamaya yazar ve TTL'sini uzatır.
+pub fn write_airdrop(e: &Env, id: u32, airdrop: &Airdrop) {
+    let key = DataKey::Airdrop(id);
+    e.storage().persistent().set(&key, airdrop);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'read_claimed_word' fonksiyonu, airdrop'un bit haritasında 'word' sıradaki 128 bitlik kelimeyi okur.
+fn read_claimed_word(e: &Env, id: u32, word: u32) -> u128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::AirdropClaimed(id, word))
+        .unwrap_or(0)
+}
+
+// 'is_leaf_claimed' fonksiyonu, airdrop'un 'index' sıradaki yaprağının talep edilip edilmediğini döndürür.
+pub fn is_leaf_claimed(e: &Env, id: u32, index: u32) -> bool {
+    read_claimed_word(e, id, index / 128) & (1 << (index % 128)) != 0
+}
+
+// 'set_leaf_claimed' fonksiyonu, airdrop'un 'index' sıradaki yaprağını talep edilmiş olarak işaretler.
+pub fn set_leaf_claimed(e: &Env, id: u32, index: u32) {
+    let key = DataKey::AirdropClaimed(id, index / 128);
+    let word = read_claimed_word(e, id, index / 128) | (1 << (index % 128));
+    e.storage().persistent().set(&key, &word);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'airdrop_leaf' fonksiyonu, 'index' sıradaki 'account' hesabına 'amount' tahsis eden yaprağın özetini hesaplar.
+pub fn airdrop_leaf(e: &Env, index: u32, account: &Address, amount: i128) -> BytesN<32> {
+    let mut data = Bytes::from_array(e, &index.to_be_bytes());
+    data.append(&account.clone().to_xdr(e));
+    data.extend_from_array(&amount.to_be_bytes());
+    e.crypto().sha256(&data).to_bytes()
+}
+
+// 'verify_proof' fonksiyonu, 'leaf' yaprağından başlayarak kanıttaki kardeş düğümlerle kökü yeniden hesaplar
+// ve 'root' ile eşleşip eşleşmediğini döndürür.
+pub fn verify_proof(e: &Env, root: &BytesN<32>, leaf: BytesN<32>, proof: &MerkleProof) -> bool {
+    let mut node = leaf;
+    let mut position = proof.index;
+    for sibling in proof.siblings.iter() {
+        let mut data = Bytes::new(e);
+        if position & 1 == 0 {
+            data.append(&node.into()); // Düğüm soldadır.
+            data.append(&sibling.into());
+        } else {
+            data.append(&sibling.into()); // Düğüm sağdadır.
+            data.append(&node.into());
+        }
+        node = e.crypto().sha256(&data).to_bytes();
+        position >>= 1;
+    }
+    position == 0 && node == *root // 'index' ağacın derinliğinden büyük olamaz.
+}
@@ -5,15 +5,71 @@
 // fonksiyonlarına benzer işlevsellik sağlar.
 // İzinler, belirli bir miktarla ve bir son geçerlilik defteri (expiration_ledger) ile tanımlanır.
 
-use crate::storage_types::{AllowanceDataKey, AllowanceValue, DataKey}; // Mevcut kütüphanenin 'storage_types' modülünden
+use crate::storage_types::{
+    AllowanceDataKey, AllowanceValue, DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};                                                                    // Mevcut kütüphanenin 'storage_types' modülünden
                                                                       // 'AllowanceDataKey', 'AllowanceValue' ve 'DataKey'
                                                                       // türlerini içeri aktarır.
                                                                       // 'AllowanceDataKey': Bir iznin sahibi (from) ve harcayıcısını (spender) tutan anahtar yapısı.
                                                                       // 'AllowanceValue': İzin verilen miktarı (amount) ve son geçerlilik defterini (expiration_ledger) tutan yapı.
                                                                       // 'DataKey': Genel depolama anahtarı enum'ı, burada 'Allowance' varyantı kullanılır.
-use soroban_sdk::{Address, Env};                                     // soroban_sdk kütüphanesinden 'Address' ve 'Env' türlerini içeri aktarır.
+                                                                      // TTL sabitleri, izin verilen adreslerin (spender) kalıcı listesi için kullanılır.
+use soroban_sdk::{Address, Env, Vec};                                // soroban_sdk kütüphanesinden 'Address', 'Env' ve 'Vec' türlerini içeri aktarır.
                                                                       // 'Address': Bir hesabı veya kontratı temsil eder.
                                                                       // 'Env': Soroban çalışma zamanı ortamına erişim sağlar.
+                                                                      // 'Vec': Bir hesabın izin verdiği adreslerin listesi için kullanılır.
+
+// 'read_spenders' fonksiyonu, 'from' adresinin harcama izni verdiği adreslerin listesini okur.
+// İzinler geçici depolamada tutulduğu için sayılamaz; bu liste, izinlerin (örneğin hesap kurtarmada)
+// başka bir hesaba taşınabilmesi için ayrıca tutulur. Liste yalnızca üyelik değiştiğinde yazılır:
+// geçerli (sıfırdan büyük ve süresi dolmamış) her izin listededir, sıfırlanan izinler hemen, süresi dolanlar ise
+// listeye bir sonraki ekleme yapılırken çıkarılır. Böylece liste, canlı izinlerin sayısıyla sınırlı kalır.
+fn read_spenders(e: &Env, from: &Address) -> Vec<Address> {
+    let key = DataKey::Spenders(from.clone());
+    if let Some(spenders) = e.storage().persistent().get::<DataKey, Vec<Address>>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        spenders
+    } else {
+        Vec::new(e)
+    }
+}
+
+// 'write_spenders' fonksiyonu, 'from' adresinin harcama izni verdiği adreslerin listesini yazar; liste boşsa girdiyi siler.
+fn write_spenders(e: &Env, from: &Address, spenders: &Vec<Address>) {
+    let key = DataKey::Spenders(from.clone());
+    if spenders.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, spenders);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+}
+
+// 'add_spender' fonksiyonu, 'spender' adresini 'from' adresinin listesine ekler. Bu sırada izni sıfırlanmış
+// veya süresi dolmuş adresler listeden çıkarılır, böylece liste sınırsız büyümez.
+fn add_spender(e: &Env, from: &Address, spender: &Address) {
+    let mut spenders = Vec::new(e);
+    for listed in read_spenders(e, from).iter() {
+        if listed != *spender && read_allowance(e, from.clone(), listed.clone()).amount > 0 {
+            spenders.push_back(listed);
+        }
+    }
+    spenders.push_back(spender.clone());
+    write_spenders(e, from, &spenders);
+}
+
+// 'remove_spender' fonksiyonu, 'spender' adresini (listedeyse) 'from' adresinin listesinden çıkarır.
+fn remove_spender(e: &Env, from: &Address, spender: &Address) {
+    let mut spenders = read_spenders(e, from);
+    if let Some(index) = spenders.first_index_of(spender) {
+        spenders.remove(index);
+        write_spenders(e, from, &spenders);
+    }
+}
 
 // 'read_allowance' fonksiyonu, belirli bir 'from' adresi tarafından 'spender' adresine verilen izni okur.
 pub fn read_allowance(e: &Env, from: Address, spender: Address) -> AllowanceValue {
@@ -80,6 +136,15 @@ pub fn write_allowance(
         panic!("expiration_ledger is less than ledger seq when amount > 0")
     }
 
+    // İzin verilen adresler listesini yalnızca üyelik değiştiğinde güncelleriz: izni sıfırlanan adres listeden
+    // çıkarılır, yeni geçerli izin alan adres listeye eklenir. Zaten geçerli bir izni olan adres listededir;
+    // böylece kısmi harcamalar ve izin güncellemeleri listeyi okumaz ve yazmaz.
+    if amount == 0 {
+        remove_spender(e, &from, &spender);
+    } else if read_allowance(e, from.clone(), spender.clone()).amount == 0 {
+        add_spender(e, &from, &spender);
+    }
+
     // İzin için depolama anahtarını oluştururuz.
     let key = DataKey::Allowance(AllowanceDataKey { from, spender });
     // Oluşturulan 'allowance' değerini, 'key' kullanarak kontratın 'temporary' depolama alanına yazarız.
@@ -135,4 +200,17 @@ pub fn spend_allowance(e: &Env, from: Address, spender: Address, amount: i128) {
         allowance.amount - amount,        // Kalan izin miktarı.
         allowance.expiration_ledger,      // Son geçerlilik defteri aynı kalır.
     );
-}
\ No newline at end of file
+}
+
+// 'move_allowances' fonksiyonu, 'from' adresinin verdiği tüm geçerli harcama izinlerini aynı miktar ve
+// son geçerlilik defteriyle 'to' adresine taşır ve 'from' adresinin izinlerini sıfırlar.
+// Hesap kurtarmada, yeni adresin eski adresin izinlerini devralması için kullanılır.
+pub fn move_allowances(e: &Env, from: &Address, to: &Address) {
+    for spender in read_spenders(e, from).iter() {
+        let allowance = read_allowance(e, from.clone(), spender.clone());
+        write_allowance(e, from.clone(), spender.clone(), 0, allowance.expiration_ledger);
+        if allowance.amount > 0 {
+            write_allowance(e, to.clone(), spender, allowance.amount, allowance.expiration_ledger);
+        }
+    }
+}
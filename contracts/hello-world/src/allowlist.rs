@@ -0,0 +1,69 @@
+// Bu dosya (allowlist.rs), 'soroban-token-contract' kütüphanesinin 'allowlist' modülünü oluşturur.
+// Bu modül, izinli (permissioned) token modunu yönetir: 'AccessMode::Allowlist' seçildiğinde yalnızca
+// izin listesindeki (KYC doğrulanmış) hesaplar token tutabilir ve alabilir. Bu, dondurma (freeze) ile
+// uygulanan engelleme listesinin (blocklist) tersidir.
+// İzin kayıtları isteğe bağlı bir son geçerlilik defteri taşır; süresi dolan kayıtlar izinsiz sayılır.
+// Erişim modu 'instance' depolamada, izin kayıtları ise 'persistent' depolamada saklanır.
+
+use crate::storage_types::{AccessMode, DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{Address, Env};
+
+// 'read_access_mode' fonksiyonu, token'ın erişim modunu okur. Ayarlanmamışsa token açık ('Open') moddadır.
+pub fn read_access_mode(e: &Env) -> AccessMode {
+    e.storage()
+        .instance()
+        .get(&DataKey::AccessMode)
+        .unwrap_or(AccessMode::Open)
+}
+
+// 'write_access_mode' fonksiyonu, token'ın erişim modunu depolamaya yazar.
+pub fn write_access_mode(e: &Env, mode: AccessMode) {
+    e.storage().instance().set(&DataKey::AccessMode, &mode);
+}
+
+// 'write_allowed' fonksiyonu, hesabı isteğe bağlı bir son geçerlilik defteriyle izin listesine ekler.
+// 'expiration_ledger' 'None' ise izin süresizdir.
+pub fn write_allowed(e: &Env, account: &Address, expiration_ledger: Option<u32>) {
+    if let Some(expiration_ledger) = expiration_ledger {
+        if expiration_ledger < e.ledger().sequence() {
+            panic!("expiration_ledger is less than ledger seq");
+        }
+    }
+    let key = DataKey::Allowed(account.clone());
+    e.storage().persistent().set(&key, &expiration_ledger);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'remove_allowed' fonksiyonu, hesabı izin listesinden çıkarır.
+pub fn remove_allowed(e: &Env, account: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Allowed(account.clone()));
+}
+
+// 'is_allowed' fonksiyonu, hesabın token tutup alabilip alamayacağını döndürür.
+// Açık modda her hesap izinlidir; izin listesi modunda hesabın süresi dolmamış bir izin kaydı olmalıdır.
+pub fn is_allowed(e: &Env, account: &Address) -> bool {
+    if read_access_mode(e) == AccessMode::Open {
+        return true;
+    }
+    let key = DataKey::Allowed(account.clone());
+    match e.storage().persistent().get::<DataKey, Option<u32>>(&key) {
+        Some(expiration_ledger) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+            expiration_ledger.is_none_or(|ledger| ledger >= e.ledger().sequence())
+        }
+        None => false,
+    }
+}
+
+// 'check_allowed' fonksiyonu, hesap izinli değilse programı paniklendirir.
+pub fn check_allowed(e: &Env, account: &Address) {
+    if !is_allowed(e, account) {
+        panic!("account is not allowed to hold tokens");
+    }
+}
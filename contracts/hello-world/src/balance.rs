@@ -4,8 +4,19 @@
 // ve bir adresten token harcamak (bakiye azaltmak) için kullanılır.
 // Bakiyeler, Soroban'ın 'persistent' (kalıcı) depolama alanında saklanır ve
 // depolama ücretlerini yönetmek için TTL (Time-To-Live) mekanizmalarıyla güncellenir.
+// Depolamada hesapların payları (share) tutulur; bakiye, 'shares' modülündeki endeksle hesaplanır.
+// Bu modülün dışındaki tüm kod token miktarlarıyla çalışır; paya çevirme yalnızca burada yapılır.
 
-use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use crate::freeze::{read_frozen_amount, write_frozen_amount}; // Hesabın kısmen dondurulmuş miktarını okumak ve taşımak için kullanılır.
+use crate::hold::read_held_total; // Hesabın aktif rezervasyonlarında (hold) tutulan toplam miktarı okumak için kullanılır.
+use crate::holders::{add_holder, is_holder, remove_holder}; // Token sahipleri kaydını (holder registry) güncellemek için kullanılır.
+use crate::rewards::checkpoint_rewards; // Pay değişmeden önce hesabın ödüllerini tahakkuk ettirmek için kullanılır.
+use crate::limits::check_limits; // En fazla sahip sayısı ve hesap başına bakiye sınırlarını uygulamak için kullanılır.
+use crate::shares::{
+    amount_to_shares_down, amount_to_shares_up, read_total_shares, shares_to_amount,
+    write_total_shares,
+}; // Miktarları paya (ve payları miktara) çevirmek ve toplam payı güncellemek için kullanılır.
+use crate::storage_types::{DataKey, EscrowDataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
 // Mevcut kütüphanenin (crate) 'storage_types' modülünden belirli öğeleri içeri aktarır:
 // - 'DataKey': Depolama anahtarlarını tanımlayan enum. Burada 'Balance' varyantı kullanılır.
 // - 'BALANCE_BUMP_AMOUNT': Kalıcı depolamadaki bir girdinin TTL'sinin ne kadar artırılacağını belirten sabit.
@@ -14,10 +25,15 @@ use soroban_sdk::{Address, Env}; // soroban_sdk kütüphanesinden 'Address' ve '
                                  // 'Address': Bir hesabı veya kontratı temsil eder.
                                  // 'Env': Soroban çalışma zamanı ortamına erişim sağlar.
 
-// 'read_balance' fonksiyonu, belirtilen 'addr' adresinin token bakiyesini okur.
-pub fn read_balance(e: &Env, addr: Address) -> i128 { // 'e': Soroban çalışma zamanı ortamı.
-                                                      // 'addr': Bakiyesi okunacak adres.
-                                                      // Fonksiyon, 'i128' türünde bir bakiye değeri döndürür.
+// 'read_balance' fonksiyonu, belirtilen 'addr' adresinin token bakiyesini (pay × güncel endeks) okur.
+pub fn read_balance(e: &Env, addr: Address) -> i128 {
+    shares_to_amount(e, read_shares(e, addr))
+}
+
+// 'read_shares' fonksiyonu, belirtilen 'addr' adresinin depolamadaki payını okur.
+pub fn read_shares(e: &Env, addr: Address) -> i128 { // 'e': Soroban çalışma zamanı ortamı.
+                                                      // 'addr': Payı okunacak adres.
+                                                      // Fonksiyon, 'i128' türünde bir pay değeri döndürür.
     let key = DataKey::Balance(addr);                 // Adresi kullanarak bakiye için bir 'DataKey' oluşturur.
                                                       // Bu anahtar, depolamada bakiyeyi bulmak için kullanılır.
 
@@ -41,11 +57,27 @@ pub fn read_balance(e: &Env, addr: Address) -> i128 { // 'e': Soroban çalışma
     }
 }
 
-// 'write_balance' fonksiyonu (bu modül içinde özeldir, 'pub' olmadığı için dışarıdan erişilemez),
-// belirtilen 'addr' adresinin bakiyesini verilen 'amount' ile günceller veya yazar.
-fn write_balance(e: &Env, addr: Address, amount: i128) { // 'e': Soroban çalışma zamanı ortamı.
-                                                         // 'addr': Bakiyesi yazılacak adres.
-                                                         // 'amount': Yazılacak yeni bakiye miktarı.
+// 'write_shares' fonksiyonu (bu modül içinde özeldir, 'pub' olmadığı için dışarıdan erişilemez),
+// belirtilen 'addr' adresinin payını verilen 'amount' ile günceller ve toplam payı farkı kadar değiştirir.
+fn write_shares(e: &Env, addr: Address, amount: i128) { // 'e': Soroban çalışma zamanı ortamı.
+                                                         // 'addr': Payı yazılacak adres.
+                                                         // 'amount': Yazılacak yeni pay miktarı.
+    let previous = read_shares(e, addr.clone());
+    checkpoint_rewards(e, &addr, previous); // Eski payla kazanılan ödülleri, pay değişmeden önce tahakkuk ettiririz.
+
+    // Toplam pay, sahip kaydındaki hesapların paylarının toplamıdır. Sahip kaydı eklenmeden önceki bakiyeler
+    // henüz kayıtta değilse toplama dahil değildir; bu yüzden yalnızca kayıtlı hesabın eski payı düşülür.
+    let counted = if is_holder(e, &addr) { previous } else { 0 };
+    write_total_shares(e, read_total_shares(e) - counted + amount);
+
+    // Token sahipleri kaydını güncelleriz: payı sıfırdan büyük olan hesap kayda eklenir,
+    // payı sıfıra düşen hesap kayıttan çıkarılır.
+    if amount > 0 {
+        add_holder(e, &addr);
+    } else {
+        remove_holder(e, &addr);
+    }
+
     let key = DataKey::Balance(addr);                    // Bakiye için depolama anahtarını oluşturur.
     e.storage().persistent().set(&key, &amount);         // Belirtilen 'key' altına yeni 'amount' (bakiye) değerini yazar.
 
@@ -56,6 +88,16 @@ fn write_balance(e: &Env, addr: Address, amount: i128) { // 'e': Soroban çalı
         .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
 }
 
+// 'register_legacy_holder' fonksiyonu, sahip kaydı eklenmeden önce bakiye almış ve o zamandan beri bakiyesi
+// değişmemiş bir hesabı sahip kaydına ekler ve payını toplam paya ekler. Kayıtlı hesaplar atlanır.
+pub fn register_legacy_holder(e: &Env, addr: &Address) {
+    let shares = read_shares(e, addr.clone());
+    if shares > 0 && !is_holder(e, addr) {
+        add_holder(e, addr);
+        write_total_shares(e, read_total_shares(e) + shares);
+    }
+}
+
 // 'receive_balance' fonksiyonu, belirtilen 'addr' adresinin bakiyesine 'amount' kadar token ekler.
 pub fn receive_balance(e: &Env, addr: Address, amount: i128) { // 'e': Soroban çalışma zamanı ortamı.
                                                                // 'addr': Token alacak adres.
@@ -63,7 +105,9 @@ pub fn receive_balance(e: &Env, addr: Address, amount: i128) { // 'e': Soroban 
     let balance = read_balance(e, addr.clone());               // Önce adresin mevcut bakiyesini okuruz.
                                                                // 'addr.clone()' kullanılır çünkü 'read_balance' 'addr' alır
                                                                // ve 'addr' daha sonra 'write_balance' için tekrar kullanılır.
-    write_balance(e, addr, balance + amount);                  // Mevcut bakiyeye 'amount' eklenir ve yeni bakiye depolamaya yazılır.
+    check_limits(e, &addr, balance, balance + amount);         // Sahip sayısı ve hesap başına bakiye sınırlarını kontrol et.
+    let shares = read_shares(e, addr.clone()) + amount_to_shares_down(e, amount);
+    write_shares(e, addr, shares);                             // Alınan miktar paya aşağı yuvarlanarak eklenir.
 }
 
 // 'spend_balance' fonksiyonu, belirtilen 'addr' adresinin bakiyesinden 'amount' kadar token harcar (azaltır).
@@ -77,5 +121,88 @@ pub fn spend_balance(e: &Env, addr: Address, amount: i128) { // 'e': Soroban ça
     if balance < amount {
         panic!("insufficient balance");
     }
-    write_balance(e, addr, balance - amount);                // Mevcut bakiyeden 'amount' çıkarılır ve yeni bakiye depolamaya yazılır.
-}
\ No newline at end of file
+
+    // Bakiyenin rezerve edilmiş (hold) kısmı harcanamaz; harcama sonrası bakiye
+    // aktif rezervasyonların toplamının altına düşmemelidir.
+    let held = read_held_total(e, &addr);
+    if balance - amount < held {
+        panic!("insufficient unheld balance");
+    }
+
+    // Kısmen dondurulmuş miktar da harcanamaz; harcama sonrası bakiye rezervasyonlar ve
+    // dondurulmuş miktarın toplamının altına düşmemelidir.
+    if balance - amount < held + read_frozen_amount(e, &addr) {
+        panic!("balance is frozen");
+    }
+    debit_shares(e, addr, amount);                           // Harcanan miktar paya yukarı yuvarlanarak düşülür.
+}
+
+// 'move_to_escrow' fonksiyonu, 'from' hesabından 'amount' kadar token'ı kontratın kendi bakiyesine (emanete) taşır.
+// Harcama kontrolleri 'spend_balance' ile aynıdır. Hesaptan düşülen pay olduğu gibi kontrata geçer; böylece endeks
+// 1.0 değilken de yuvarlama emaneti eksik bırakmaz.
+pub fn move_to_escrow(e: &Env, from: Address, amount: i128) {
+    let before = read_shares(e, from.clone());
+    spend_balance(e, from.clone(), amount);
+    let moved = before - read_shares(e, from);
+    let contract = e.current_contract_address();
+    write_shares(e, contract.clone(), read_shares(e, contract) + moved);
+}
+
+// 'move_from_escrow' fonksiyonu, kontratın emanetinden 'to' hesabına 'amount' kadar token öder. Alıcıya eklenen
+// pay kontratın payından düşülür; emanet yetersizse program panikler.
+pub fn move_from_escrow(e: &Env, to: Address, amount: i128) {
+    let before = read_shares(e, to.clone());
+    receive_balance(e, to.clone(), amount);
+    let moved = read_shares(e, to) - before;
+    let contract = e.current_contract_address();
+    let remaining = read_shares(e, contract.clone()) - moved;
+    if remaining < 0 {
+        panic!("insufficient escrow balance");
+    }
+    write_shares(e, contract, remaining);
+}
+
+// 'read_escrow_total' fonksiyonu, kontratın bakiyesinde ödenmek üzere ayrılmış toplam miktarı okur. Kontrat
+// bakiyesinin geri kalanı ödül emanetidir.
+pub fn read_escrow_total(e: &Env) -> i128 {
+    e.storage().instance().get(&EscrowDataKey::Total).unwrap_or(0)
+}
+
+// 'write_escrow_total' fonksiyonu, kontratın bakiyesinde ödenmek üzere ayrılmış toplam miktarı yazar.
+pub fn write_escrow_total(e: &Env, amount: i128) {
+    e.storage().instance().set(&EscrowDataKey::Total, &amount);
+}
+
+// 'force_move_balance' fonksiyonu, yöneticinin zorunlu transferi (örneğin anahtarı kaybolan hesabın kurtarılması)
+// için 'from' adresinden 'to' adresine 'amount' kadar token taşır. Kısmen dondurulmuş miktar harcamayı engellemez;
+// bunun yerine kalan bakiyenin artık karşılayamadığı dondurulmuş kısım 'to' adresine taşınır, böylece kilit korunur.
+// Rezerve edilmiş (hold) bakiye operatöre ayrıldığı için taşınamaz.
+pub fn force_move_balance(e: &Env, from: Address, to: Address, amount: i128) {
+    let balance = read_balance(e, from.clone());
+    if balance < amount {
+        panic!("insufficient balance");
+    }
+    let held = read_held_total(e, &from);
+    if balance - amount < held {
+        panic!("insufficient unheld balance");
+    }
+
+    // Kalan bakiyenin (rezervasyonlar düşüldükten sonra) karşılayabildiği dondurulmuş miktar 'from' hesabında kalır.
+    let frozen = read_frozen_amount(e, &from);
+    let kept = frozen.min(balance - amount - held);
+
+    debit_shares(e, from.clone(), amount);
+    receive_balance(e, to.clone(), amount);
+    if kept < frozen {
+        write_frozen_amount(e, &from, kept);
+        write_frozen_amount(e, &to, read_frozen_amount(e, &to) + frozen - kept);
+    }
+}
+
+// 'debit_shares' fonksiyonu, 'addr' adresinin payından 'amount' miktarına karşılık gelen payı (yukarı yuvarlanmış)
+// düşer. Çağıranlar bakiyenin yeterli olduğunu önceden doğrular; bakiye pay × endeksin aşağı yuvarlanmış hali
+// olduğundan, yukarı yuvarlanmış pay hiçbir zaman hesabın payını aşmaz.
+fn debit_shares(e: &Env, addr: Address, amount: i128) {
+    let shares = read_shares(e, addr.clone()) - amount_to_shares_up(e, amount);
+    write_shares(e, addr, shares);
+}
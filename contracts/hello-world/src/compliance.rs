@@ -0,0 +1,50 @@
+// Bu dosya (compliance.rs), 'soroban-token-contract' kütüphanesinin 'compliance' modülünü oluşturur.
+// Her düzenleyici kuralı 'contract.rs' içine gömmek, her kural değişikliğini bir kontrat yükseltmesine dönüştürür.
+// Bu modül, kuralları harici bir uyumluluk (compliance) kontratına devretmeyi sağlar: yönetici bir kontrat adresi
+// kaydettiğinde, token her transfer, basım (mint) ve yakma (burn) öncesinde o kontratın 'can_transfer'
+// fonksiyonunu çağırır ve işlem tamamlandıktan sonra 'transferred' ile bildirim gönderir.
+// Basımda gönderen ('from'), yakmada ise alıcı ('to') olarak token kontratının kendi adresi kullanılır.
+
+use crate::storage_types::DataKey;
+use soroban_sdk::{contractclient, Address, Env};
+
+// 'ComplianceInterface', uyumluluk kontratlarının uygulaması gereken arayüzdür.
+// 'contractclient' makrosu, bu arayüz için 'ComplianceClient' adlı bir istemci üretir.
+#[allow(dead_code)] // Arayüz yalnızca istemci üretmek için kullanılır; hiçbir tür tarafından uygulanmaz.
+#[contractclient(name = "ComplianceClient")]
+pub trait ComplianceInterface {
+    // Transferin kurallara uyup uymadığını döndürür.
+    fn can_transfer(env: Env, from: Address, to: Address, amount: i128) -> bool;
+    // Başarılı bir transferden sonra çağrılır.
+    fn transferred(env: Env, from: Address, to: Address, amount: i128);
+}
+
+// 'read_compliance' fonksiyonu, kayıtlı uyumluluk kontratının adresini (varsa) okur.
+pub fn read_compliance(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Compliance)
+}
+
+// 'write_compliance' fonksiyonu, uyumluluk kontratının adresini ayarlar; 'None' kaydı kaldırır.
+pub fn write_compliance(e: &Env, compliance: &Option<Address>) {
+    match compliance {
+        Some(compliance) => e.storage().instance().set(&DataKey::Compliance, compliance),
+        None => e.storage().instance().remove(&DataKey::Compliance),
+    }
+}
+
+// 'check_compliance' fonksiyonu, kayıtlı bir uyumluluk kontratı varsa transferi ona sorar;
+// kontrat transfere izin vermezse program panikler.
+pub fn check_compliance(e: &Env, from: &Address, to: &Address, amount: i128) {
+    if let Some(compliance) = read_compliance(e) {
+        if !ComplianceClient::new(e, &compliance).can_transfer(from, to, &amount) {
+            panic!("transfer rejected by compliance");
+        }
+    }
+}
+
+// 'notify_compliance' fonksiyonu, kayıtlı bir uyumluluk kontratı varsa tamamlanan transferi bildirir.
+pub fn notify_compliance(e: &Env, from: &Address, to: &Address, amount: i128) {
+    if let Some(compliance) = read_compliance(e) {
+        ComplianceClient::new(e, &compliance).transferred(from, to, &amount);
+    }
+}
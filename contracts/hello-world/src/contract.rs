@@ -1,25 +1,92 @@
 // Bu dosya (contract.rs), 'soroban-token-contract' kütüphanesinin ana akıllı kontrat mantığını içerir.
-// 'Token' adında bir kontrat tanımlar ve bu kontrat için hem özel işlevler (initialize, mint, set_admin, freeze_account, unfreeze_account)
+// 'Token' adında bir kontrat tanımlar ve bu kontrat için hem özel işlevler (mint, set_admin, freeze_account, unfreeze_account)
 // hem de Soroban'ın standart token arayüzünü (soroban_sdk::token::Interface) uygular.
 // Bu sayede, kontrat hem temel token işlemlerini (transfer, bakiye sorgulama, onaylama vb.)
 // hem de kontrata özgü yönetim ve ek özellikleri (hesap dondurma gibi) destekler.
 // Diğer modüllerde (admin, allowance, balance, metadata) tanımlanan fonksiyonları kullanarak
 // kontratın durumunu yönetir ve işlemler gerçekleştirir.
 
-use crate::admin::{has_administrator, read_administrator, write_administrator}; // Yönetici (admin) ile ilgili fonksiyonları 'admin' modülünden alır.
-use crate::allowance::{read_allowance, spend_allowance, write_allowance};     // Harcama izinleri (allowance) ile ilgili fonksiyonları 'allowance' modülünden alır.
-use crate::balance::{read_balance, receive_balance, spend_balance};           // Bakiye (balance) ile ilgili fonksiyonları 'balance' modülünden alır.
-use crate::metadata::{read_decimal, read_name, read_symbol, write_metadata};   // Token meta verileri (isim, sembol, ondalık) ile ilgili fonksiyonları 'metadata' modülünden alır.
+use crate::admin::{
+    has_role, read_administrator, read_treasury, remove_administrator, require_admin, require_admin_or_role, try_read_administrator,
+    write_administrator, write_role, write_treasury,
+};                                                                            // Yönetici (admin) ve hazine (treasury) ile ilgili fonksiyonları 'admin' modülünden alır.
+use crate::airdrop::{
+    airdrop_leaf, is_leaf_claimed, next_airdrop_id, read_airdrop, read_airdrop_count, set_leaf_claimed,
+    verify_proof, write_airdrop,
+}; // Merkle airdrop'ları ve talep bit haritaları ile ilgili fonksiyonları 'airdrop' modülünden alır.
+use crate::allowance::{move_allowances, read_allowance, spend_allowance, write_allowance}; // Harcama izinleri (allowance) ile ilgili fonksiyonları 'allowance' modülünden alır.
+use crate::allowlist::{
+    check_allowed, is_allowed, read_access_mode, remove_allowed, write_access_mode, write_allowed,
+};                                                                            // İzin listesi (allowlist) modu ile ilgili fonksiyonları 'allowlist' modülünden alır.
+use crate::balance::{
+    force_move_balance, move_from_escrow, move_to_escrow, read_balance, read_escrow_total, read_shares,
+    receive_balance, spend_balance, write_escrow_total,
+}; // Bakiye (balance) ile ilgili fonksiyonları 'balance' modülünden alır.
+use crate::compliance::{check_compliance, notify_compliance, read_compliance, write_compliance}; // Harici uyumluluk kontratı ile ilgili fonksiyonları 'compliance' modülünden alır.
+use crate::council::{
+    count_approvals, next_proposal_id, read_admin_council, read_proposal, remove_admin_council,
+    remove_proposal, require_council, require_member, write_admin_council, write_proposal,
+};                                                                            // Yönetici konseyi ve önerileri ile ilgili fonksiyonları 'council' modülünden alır.
+use crate::freeze::{
+    is_account_frozen, is_freeze_active, read_freeze, read_frozen_amount, remove_freeze,
+    write_freeze, write_frozen_amount,
+};                                                                            // Hesap dondurma kayıtları ile ilgili fonksiyonları 'freeze' modülünden alır.
+use crate::hold::{
+    add_account_hold, is_hold_active, next_hold_id, read_held_total, read_hold, remove_hold,
+    try_read_hold, write_hold,
+};                                                                            // Bakiye rezervasyonu (hold) ile ilgili fonksiyonları 'hold' modülünden alır.
+use crate::holders::{read_holder_count, read_holders};                       // Token sahipleri kaydını okuyan fonksiyonları 'holders' modülünden alır.
+use crate::limits::{
+    read_max_balance_per_account, read_max_holders, write_max_balance_per_account,
+    write_max_holders,
+};                                                                            // Sahip ve bakiye sınırları ile ilgili fonksiyonları 'limits' modülünden alır.
+use crate::identity::{
+    check_required_claims, read_required_claims, read_valid_claims, remove_claim, write_claim,
+    write_required_claims,
+};                                                                            // Kimlik beyanları (claim) ile ilgili fonksiyonları 'identity' modülünden alır.
+use crate::metadata::{
+    check_name_symbol, read_decimal, read_metadata_field, read_name, read_symbol, write_metadata,
+    write_metadata_field, write_name_symbol,
+};                                                                            // Token meta verileri (isim, sembol, ondalık) ile ilgili fonksiyonları 'metadata' modülünden alır.
+use crate::recovery::{
+    add_approval, read_guardians, read_recovery, remove_recovery, require_guardian, write_guardians,
+    write_recovery,
+};                                                                            // Sosyal kurtarma (koruyucular ve bekleyen kurtarmalar) ile ilgili fonksiyonları 'recovery' modülünden alır.
 use crate::storage_types::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD}; // Kontrat örneği depolaması için TTL (Time-To-Live) sabitlerini 'storage_types' modülünden alır.
-use crate::storage_types::DataKey;                                            // Genel depolama anahtarı enum'ını 'storage_types' modülünden alır.
+use crate::storage_types::{
+    AccessMode, AdminCouncil, AdminOp, Airdrop, Claim, ClaimTopic, FlashLoan, FreezeInfo,
+    GuardianConfig, Hold, MerkleProof, Proposal, Recovery, Role, ScheduledOp, Stream,
+}; // Depolama anahtarlarını, kontrat arayüzünde kullanılan enum'ları ve kayıt yapılarını 'storage_types' modülünden alır.
+use crate::stream::{
+    next_stream_id, read_stream, remove_stream, streamed_amount, withdrawable_amount, write_stream,
+};                                                                            // Ödeme akışı (stream) ile ilgili fonksiyonları 'stream' modülünden alır.
+use crate::timelock::{
+    clear_scheduled_ops, earliest_eta, is_timelocked, next_op_id, read_pending_ops, read_scheduled_op,
+    read_timelock_delay, remove_scheduled_op, require_no_timelock, write_scheduled_op, write_timelock_delay,
+};                                                                            // Yönetici işlemlerinin zaman kilidi kuyruğu ile ilgili fonksiyonları 'timelock' modülünden alır.
+use crate::upgrade::{finish_migration, migrate_accounts, read_version, write_version, CONTRACT_VERSION}; // Kontrat sürümü ve depolama geçişleri ile ilgili fonksiyonları 'upgrade' modülünden alır.
+use crate::flash::{
+    close_flash_loan, flash_fee, open_flash_loan, read_flash_fee_bps, read_flash_lender,
+    read_max_flash_loan, write_flash_lender, write_flash_loan_config,
+};                                                                            // Anlık krediler (flash loan) ile ilgili fonksiyonları 'flash' modülünden alır.
+use crate::rewards::{
+    add_distribution, add_reward_token, pending_rewards, read_reward_tokens, remove_reward_token,
+    take_rewards,
+}; // Ödül dağıtımı ve talepleri ile ilgili fonksiyonları 'rewards' modülünden alır.
+use crate::shares::{read_index, read_total_shares, write_index};             // Pay tabanlı muhasebenin endeksi ve toplam payı ile ilgili fonksiyonları 'shares' modülünden alır.
+use crate::wrapper::{read_underlying, require_underlying, write_underlying};  // Sarmalayıcı (wrapper) modu ile ilgili fonksiyonları 'wrapper' modülünden alır.
 use soroban_sdk::token::{self, Interface as _};                              // Soroban SDK'sının standart token arayüzünü ve ilgili özellikleri alır.
                                                                               // 'Interface as _' yapısı, arayüzdeki metodları doğrudan çağırabilmek için kullanılır.
-use soroban_sdk::{contract, contractimpl, Address, Env, String};              // Soroban SDK'sının temel kontrat geliştirme araçlarını alır:
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, IntoVal, String, Symbol, Val, Vec}; // Soroban SDK'sının temel kontrat geliştirme araçlarını alır:
                                                                               // 'contract': Bir struct'ı Soroban kontratı olarak işaretler.
                                                                               // 'contractimpl': Bir impl bloğunu kontrat fonksiyonlarını içeriyor olarak işaretler.
                                                                               // 'Address': Soroban adres türü.
+                                                                              // 'BytesN': Sabit uzunluklu bayt dizisi; yükseltmede Wasm kod özeti (hash) için kullanılır.
                                                                               // 'Env': Kontratın çalıştığı ortam (environment).
                                                                               // 'String': Soroban için optimize edilmiş string türü.
+                                                                              // 'Symbol': Genişletilmiş meta veri alanlarının anahtarı olarak kullanılan kısa string türü.
+                                                                              // 'Vec': Soroban için optimize edilmiş vektör türü.
+                                                                              // 'IntoVal', 'Val': Olay veri yüklerini Soroban değerlerine dönüştürmek için kullanılır.
 use soroban_token_sdk::metadata::TokenMetadata;                               // soroban_token_sdk'dan 'TokenMetadata' yapısını alır, token meta verilerini tutmak için kullanılır.
 use soroban_token_sdk::TokenUtils;                                            // soroban_token_sdk'dan 'TokenUtils' alır, genellikle standart token olaylarını (events) yayınlamak için kullanılır.
 
@@ -32,142 +99,1908 @@ fn check_nonnegative_amount(amount: i128) {
     }
 }
 
-// 'is_account_frozen' yardımcı fonksiyonu, bir hesabın dondurulup dondurulmadığını kontrol eder.
-fn is_account_frozen(e: &Env, account: &Address) -> bool { // 'e': Çalışma ortamı, 'account': Kontrol edilecek hesap adresi.
-    let key = DataKey::Frozen(account.clone());            // Hesap için dondurma durumunu saklayan depolama anahtarını oluştur.
-                                                           // '.clone()' ile 'account' adresinin bir kopyası kullanılır.
-    e.storage()                                            // Kontratın depolama alanına eriş.
-        .instance()                                        // 'instance' (örnek) depolama türünü kullan.
-        .get::<_, bool>(&key)                              // Belirtilen anahtarla depodan boolean bir değer oku.
-        .unwrap_or(false)                                  // Eğer değer varsa onu, yoksa 'false' (dondurulmamış) döndür.
+// 'emit_custom_event' yardımcı fonksiyonu, özel olayları (events) yayınlamak için kullanılır.
+fn emit_custom_event<D: IntoVal<Env, Val>>(e: &Env, event_type: &str, admin: Address, account: Address, data: D) { // 'e': Çalışma ortamı, 'event_type': Olayın türünü belirten string, 'admin': Olayla ilişkili admin, 'account': Olayla ilişkili hesap, 'data': Olayın veri yükü.
+    e.events()                                             // Ortamın olay yöneticisine eriş.
+        .publish((event_type, admin, account), data);      // Belirtilen verilerle bir olay yayınla. Veri yükü olmayan olaylar için `()` gönderilir.
+}
+
+// 'apply_admin_op' yardımcı fonksiyonu, bir yönetici işlemini uygular ve olayını yayınlar.
+// Tek yöneticili giriş noktaları, zaman kilidi kuyruğu ('execute') ve yönetici konseyi aynı uygulamayı kullanır;
+// 'admin' olaylarda işlemi yapan yetkili olarak yer alır (konsey için kontratın kendi adresi).
+fn apply_admin_op(e: &Env, admin: Address, op: AdminOp) {
+    match op {
+        AdminOp::SetAdmin(new_admin) => {
+            write_administrator(e, &new_admin); // Yeni yönetici adresini depolamaya yaz.
+            remove_admin_council(e);            // Yönetim yeniden tek bir adrese geçer.
+            clear_scheduled_ops(e);             // Eski yönetimin planladığı işlemler yeni yönetici altında yürütülmez.
+            TokenUtils::new(e).events().set_admin(admin, new_admin); // Standart 'set_admin' olayını yayınla.
+        }
+        AdminOp::SetMaxHolders(max_holders) => {
+            write_max_holders(e, max_holders);
+            e.events().publish(("set_max_holders", admin), max_holders);
+        }
+        AdminOp::SetMaxBalancePerAccount(max_balance) => {
+            if let Some(max_balance) = max_balance {
+                check_nonnegative_amount(max_balance); // Sınırın negatif olmadığını kontrol et.
+            }
+            write_max_balance_per_account(e, max_balance);
+            e.events()
+                .publish(("set_max_balance_per_account", admin), max_balance);
+        }
+        AdminOp::SetCompliance(compliance) => {
+            write_compliance(e, &compliance);
+            e.events().publish(("set_compliance", admin), compliance);
+        }
+        AdminOp::Upgrade(new_wasm_hash) => {
+            e.deployer()
+                .update_current_contract_wasm(new_wasm_hash.clone());
+            e.events().publish(("upgrade", admin), new_wasm_hash); // Yeni Wasm kod özetini içeren olay.
+        }
+        AdminOp::SetTimelockDelay(delay) => {
+            write_timelock_delay(e, delay);
+            e.events().publish(("set_timelock_delay", admin), delay);
+        }
+        AdminOp::SetAdminCouncil(members, threshold) => {
+            write_admin_council(e, &AdminCouncil { members: members.clone(), threshold });
+            // Konsey ayarlıyken yönetici kaydı kontratın kendi adresini gösterir; tek yöneticili giriş noktaları kapanır.
+            write_administrator(e, &e.current_contract_address());
+            clear_scheduled_ops(e); // Eski yönetimin planladığı işlemler konsey altında yürütülmez.
+            e.events()
+                .publish(("set_admin_council", admin), (members, threshold));
+        }
+        AdminOp::RenounceAdmin => {
+            remove_administrator(e);         // Yönetici kaydını sil ve kontratı yeniden kurulamaz olarak işaretle.
+            remove_admin_council(e);         // Varsa yönetici konseyini de kaldır.
+            clear_scheduled_ops(e);          // Artık yürütülemeyecek planlanmış işlemleri temizle.
+            e.events().publish(("renounce_admin", admin), ());
+        }
+        AdminOp::Mint(to, amount) => {
+            if read_underlying(e).is_some() {
+                // Sarılmış token'lar yalnızca 'deposit' ile basılır; aksi halde 1:1 karşılık bozulur.
+                panic!("minting is disabled for wrapped tokens");
+            }
+            check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
+            check_allowed(e, &to); // İzin listesi modunda alıcının izinli olduğunu kontrol et.
+            check_compliance(e, &e.current_contract_address(), &to, amount); // Uyumluluk kontratı basıma izin veriyor mu kontrol et.
+
+            receive_balance(e, to.clone(), amount); // 'to' adresinin bakiyesine 'amount' ekle.
+            notify_compliance(e, &e.current_contract_address(), &to, amount); // Uyumluluk kontratına basımı bildir.
+            TokenUtils::new(e).events().mint(admin, to, amount); // Standart 'mint' olayını yayınla.
+        }
+        AdminOp::FreezeAccount(account, reason, unfreeze_at_ledger) => {
+            check_unfreeze_ledger(e, unfreeze_at_ledger);
+
+            // Hesabın dondurma kaydını depolamaya yazarız.
+            write_freeze(
+                e,
+                &account,
+                &FreezeInfo {
+                    reason,
+                    authority: admin.clone(),
+                    frozen_at_ledger: e.ledger().sequence(),
+                    unfreeze_at_ledger,
+                },
+            );
+
+            // Neden kodunu ve çözülme defterini içeren özel bir 'freeze_account' olayı yayınla.
+            emit_custom_event(e, "freeze_account", admin, account, (reason, unfreeze_at_ledger));
+        }
+        AdminOp::UnfreezeAccount(account) => {
+            // Kaldırılan dondurmanın neden kodunu olaya eklemek için kaydı okur, ardından depolamadan sileriz.
+            let reason = read_freeze(e, &account).map(|info| info.reason);
+            remove_freeze(e, &account);
+
+            // Kaldırılan dondurmanın neden kodunu içeren özel bir 'unfreeze_account' olayı yayınla.
+            emit_custom_event(e, "unfreeze_account", admin, account, reason);
+        }
+        AdminOp::FreezeAccounts(accounts, reason, unfreeze_at_ledger) => {
+            check_unfreeze_ledger(e, unfreeze_at_ledger);
+
+            let info = FreezeInfo {
+                reason,
+                authority: admin.clone(),
+                frozen_at_ledger: e.ledger().sequence(),
+                unfreeze_at_ledger,
+            };
+            for account in accounts.iter() {
+                if is_account_frozen(e, &account) {
+                    continue; // Zaten dondurulmuş hesabın mevcut kaydı korunur.
+                }
+                write_freeze(e, &account, &info);
+                emit_custom_event(e, "freeze_account", admin.clone(), account, (reason, unfreeze_at_ledger));
+            }
+        }
+        AdminOp::UnfreezeAccounts(accounts) => {
+            for account in accounts.iter() {
+                let Some(info) = read_freeze(e, &account) else {
+                    continue; // Dondurma kaydı olmayan hesap atlanır.
+                };
+                remove_freeze(e, &account);
+                if is_freeze_active(e, &info) {
+                    emit_custom_event(e, "unfreeze_account", admin.clone(), account, Some(info.reason));
+                }
+            }
+        }
+        AdminOp::FreezeAmount(account, amount) => {
+            check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
+
+            let frozen = read_frozen_amount(e, &account) + amount;
+            write_frozen_amount(e, &account, frozen);
+
+            // Dondurulan ve toplam dondurulmuş miktarı içeren özel bir 'freeze_amount' olayı yayınla.
+            emit_custom_event(e, "freeze_amount", admin, account, (amount, frozen));
+        }
+        AdminOp::UnfreezeAmount(account, amount) => {
+            check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
+
+            let frozen = read_frozen_amount(e, &account);
+            if amount > frozen {
+                panic!("amount exceeds frozen amount");
+            }
+            write_frozen_amount(e, &account, frozen - amount);
+
+            // Çözülen ve kalan dondurulmuş miktarı içeren özel bir 'unfreeze_amount' olayı yayınla.
+            emit_custom_event(e, "unfreeze_amount", admin, account, (amount, frozen - amount));
+        }
+        AdminOp::ForceTransfer(from, to, amount, reason) => {
+            check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
+            check_allowed(e, &to);         // İzin listesi modunda alıcının izinli olduğunu kontrol et.
+            check_required_claims(e, &to); // Alıcının gerekli kimlik beyanlarına sahip olduğunu kontrol et.
+
+            force_move_balance(e, from.clone(), to.clone(), amount); // Bakiyeyi ve karşılanamayan dondurulmuş miktarı taşı.
+            notify_compliance(e, &from, &to, amount); // Uyumluluk kontratının bakiye yansımasını güncel tut.
+
+            // Miktarı ve neden kodunu içeren ayrı bir 'force_transfer' olayı yayınla.
+            e.events()
+                .publish(("force_transfer", admin, from, to), (amount, reason));
+        }
+        AdminOp::SetTreasury(treasury) => {
+            write_treasury(e, &treasury);
+            emit_custom_event(e, "set_treasury", admin, treasury, ());
+        }
+        AdminOp::GrantRole(role, account) => {
+            write_role(e, role, &account, true);
+            e.events().publish(("grant_role", admin, account), role);
+        }
+        AdminOp::RevokeRole(role, account) => {
+            write_role(e, role, &account, false);
+            e.events().publish(("revoke_role", admin, account), role);
+        }
+        AdminOp::SetRequiredClaims(topics) => {
+            write_required_claims(e, &topics);
+            e.events().publish(("set_required_claims", admin), topics);
+        }
+        AdminOp::SetFlashLoanConfig(fee_bps, max_amount) => {
+            write_flash_loan_config(e, fee_bps, max_amount);
+            e.events()
+                .publish(("set_flash_loan_config", admin), (fee_bps, max_amount));
+        }
+        AdminOp::SetFlashLender(lender) => {
+            write_flash_lender(e, &lender);
+            e.events().publish(("set_flash_lender", admin), lender);
+        }
+        AdminOp::AddRewardToken(reward_token) => {
+            if read_underlying(e) == Some(reward_token.clone()) {
+                panic!("cannot distribute the underlying token"); // Sarmalayıcının 1:1 karşılığı ödül olarak kullanılamaz.
+            }
+            add_reward_token(e, &reward_token);
+            e.events().publish(("add_reward_token", admin), reward_token);
+        }
+        AdminOp::RemoveRewardToken(reward_token) => {
+            remove_reward_token(e, &reward_token);
+            e.events().publish(("remove_reward_token", admin), reward_token);
+        }
+        AdminOp::CreateAirdrop(root, total, expiration_ledger) => {
+            check_nonnegative_amount(total); // Toplam miktarın negatif olmadığını kontrol et.
+            if expiration_ledger < e.ledger().sequence() {
+                panic!("expiration_ledger is less than ledger seq");
+            }
+
+            // Toplam miktarı hazineden kontratın emanetine aktar; hazinenin de işlemi imzalaması gerekir.
+            let treasury = read_treasury(e).unwrap_or_else(|| panic!("treasury is not set"));
+            treasury.require_auth();
+            if is_account_frozen(e, &treasury) {
+                panic!("Hesap dondurulmuş ve token transfer edilemez"); // Dondurulmuşsa hata ver.
+            }
+            let contract = e.current_contract_address();
+            check_compliance(e, &treasury, &contract, total); // Uyumluluk kontratı transfere izin veriyor mu kontrol et.
+            move_to_escrow(e, treasury.clone(), total);
+            write_escrow_total(e, read_escrow_total(e) + total);
+            notify_compliance(e, &treasury, &contract, total); // Uyumluluk kontratına transferi bildir.
+            TokenUtils::new(e).events().transfer(treasury, contract, total); // Emanete alımı bildiren standart 'transfer' olayı.
+
+            let id = next_airdrop_id(e); // Yeni airdrop için kimlik üret.
+            write_airdrop(
+                e,
+                id,
+                &Airdrop { root: root.clone(), total, claimed: 0, expiration_ledger },
+            );
+            e.events()
+                .publish(("create_airdrop", admin), (id, root, total, expiration_ledger));
+        }
+        AdminOp::Migrate(accounts) => {
+            let count = migrate_accounts(e, &accounts);
+
+            // Taşınan hesap sayısını içeren bir 'migrate' olayı yayınla.
+            e.events().publish(("migrate", admin), count);
+        }
+        AdminOp::FinishMigration => {
+            let (from, to) = finish_migration(e);
+
+            // Eski ve yeni sürümü içeren bir 'finish_migration' olayı yayınla.
+            e.events().publish(("finish_migration", admin), (from, to));
+        }
+        AdminOp::ExecuteScheduled(op_id) => {
+            let scheduled = read_scheduled_op(e, op_id);
+            if e.ledger().sequence() < scheduled.eta_ledger {
+                panic!("operation is not ready for execution");
+            }
+            remove_scheduled_op(e, op_id);
+
+            // Yürütülen işlemi içeren bir 'execute' olayı yayınla, ardından işlemi uygula.
+            e.events()
+                .publish(("execute", admin.clone(), op_id), scheduled.op.clone());
+            apply_admin_op(e, admin, scheduled.op);
+        }
+        AdminOp::CancelScheduled(op_id) => {
+            let scheduled = read_scheduled_op(e, op_id);
+            remove_scheduled_op(e, op_id);
+
+            // İptal edilen işlemi içeren bir 'cancel' olayı yayınla.
+            e.events().publish(("cancel", admin, op_id), scheduled.op);
+        }
+    }
+}
+
+// 'schedule_admin_op' yardımcı fonksiyonu, zaman kilitli bir işlemi 'eta_ledger' defterinde yürütülmek üzere
+// kuyruğa ekler ve kimliğini döndürür. 'eta_ledger', güncel defterden en az bekleme süresi kadar ileride olmalıdır.
+fn schedule_admin_op(e: &Env, admin: Address, op: AdminOp, eta_ledger: u32) -> u32 {
+    if !is_timelocked(&op) {
+        panic!("operation is not timelocked");
+    }
+    if eta_ledger < earliest_eta(e) {
+        panic!("eta_ledger is earlier than the minimum delay");
+    }
+
+    let id = next_op_id(e);
+    write_scheduled_op(
+        e,
+        &ScheduledOp {
+            id,
+            op: op.clone(),
+            eta_ledger,
+        },
+    );
+
+    // İşlemi ve yürütülebileceği defteri içeren bir 'schedule' olayı yayınla.
+    e.events().publish(("schedule", admin, id), (op, eta_ledger));
+    id
+}
+
+// 'dispatch_council_op' yardımcı fonksiyonu, yönetici konseyinin onayladığı bir işlemi yürütür.
+// Zaman kilidi etkinse zaman kilitli işlemler uygulanmak yerine en erken defterde yürütülmek üzere kuyruğa eklenir.
+fn dispatch_council_op(e: &Env, op: AdminOp) {
+    let admin = read_administrator(e); // Konsey ayarlıyken kontratın kendi adresidir.
+    let delay = read_timelock_delay(e);
+    if delay > 0 && is_timelocked(&op) {
+        schedule_admin_op(e, admin, op, earliest_eta(e));
+    } else {
+        apply_admin_op(e, admin, op);
+    }
+}
+
+// 'check_unfreeze_ledger' yardımcı fonksiyonu, otomatik çözülme defteri verilmişse gelecekte olduğunu kontrol eder.
+fn check_unfreeze_ledger(e: &Env, unfreeze_at_ledger: Option<u32>) {
+    if let Some(ledger) = unfreeze_at_ledger {
+        if ledger <= e.ledger().sequence() {
+            panic!("unfreeze_at_ledger must be greater than ledger seq");
+        }
+    }
 }
 
-// 'emit_custom_event' yardımcı fonksiyonu, özel olayları (events) yayınlamak için kullanılır.
-fn emit_custom_event(e: &Env, event_type: &str, admin: Address, account: Address) { // 'e': Çalışma ortamı, 'event_type': Olayın türünü belirten string, 'admin': Olayla ilişkili admin, 'account': Olayla ilişkili hesap.
-    e.events()                                             // Ortamın olay yöneticisine eriş.
-        .publish((event_type, admin, account), ());        // Belirtilen verilerle bir olay yayınla. İkinci tuple `()` boş veri yükünü temsil eder.
-}
+#[contract] // Bu struct'ın bir Soroban akıllı kontratı olduğunu belirtir.
+pub struct Token; // 'Token' adlı kontrat yapısı. Bu yapı, kontratın durumunu değil, tipini tanımlar.
+                  // Kontratın durumu 'Env' üzerinden erişilen depolamada tutulur.
+
+#[contractimpl] // Bu blok, 'Token' kontratı için fonksiyonları (metodları) içerir.
+impl Token {
+    // '__constructor' fonksiyonu, kontrat dağıtılırken (deploy) Soroban tarafından tek bir işlemde çağrılır.
+    // Yöneticiyi (admin), ondalık sayısını, ismini, sembolünü ve erişim modunu dağıtımla aynı anda ayarlar;
+    // böylece yeni dağıtılmış bir örneği başkasının bizden önce başlatıp yönetici olması mümkün değildir.
+    // Kurucu yalnızca dağıtımda bir kez çalışır ve ayrı bir başlatma ('initialize') fonksiyonu yoktur;
+    // önceki sürümlerle kurulmuş örnekler yükseltmede yöneticilerini ve depolamalarını korur.
+    pub fn __constructor(
+        e: Env,
+        admin: Address,
+        decimal: u32,
+        name: String,
+        symbol: String,
+        mode: AccessMode,
+        underlying: Option<Address>,
+    ) {
+        // 'e': Çalışma ortamı.
+        // 'admin': Kontratın yöneticisi olacak adres.
+        // 'decimal': Token'ın ondalık basamak sayısı.
+        // 'name': Token'ın adı.
+        // 'symbol': Token'ın sembolü.
+        // 'mode': Token'ın açık ('Open') mı yoksa izin listeli ('Allowlist') mi olacağı.
+        // 'underlying': Sarmalayıcı modunda 1:1 sarılacak SEP-41 token'ın adresi; normal token için 'None'.
+        write_administrator(&e, &admin); // 'admin' modülünü kullanarak yönetici adresini depolamaya yaz.
+        write_access_mode(&e, mode);     // 'allowlist' modülünü kullanarak erişim modunu depolamaya yaz.
+        write_version(&e, CONTRACT_VERSION); // Yeni kurulan kontrat, güncel depolama düzeniyle başlar.
+
+        if decimal > u8::MAX.into() { // Eğer ondalık sayısı bir u8'e sığmayacak kadar büyükse...
+                                      // u8::MAX, 8-bitlik işaretsiz bir tamsayının alabileceği maksimum değerdir (255).
+                                      // '.into()' ile u32'den u8 karşılaştırması için dönüştürme yapılır.
+            panic!("Decimal must fit in a u8"); // Hata ver. Standart token arayüzü ondalık için u8 bekler.
+        }
+        check_name_symbol(&e, &name, &symbol); // Boş veya aşırı uzun adı ve geçersiz sembolü tipli bir hatayla reddet.
+        if let Some(underlying) = underlying {
+            write_underlying(&e, &underlying, decimal); // Sarmalayıcı modunda alttaki token'ı kaydet.
+        }
+
+        // 'metadata' modülünü kullanarak token'ın meta verilerini (ondalık, isim, sembol) depolamaya yaz.
+        write_metadata(
+            &e,
+            TokenMetadata { // 'TokenMetadata' yapısını oluştur.
+                decimal,
+                name,
+                symbol,
+            },
+        )
+    }
+
+    // 'mint' fonksiyonu, belirli bir 'to' adresine 'amount' kadar yeni token üretir (basar).
+    // Sadece yönetici (admin) tarafından çağrılabilir.
+    pub fn mint(e: Env, to: Address, amount: i128) {
+        // 'e': Çalışma ortamı.
+        // 'to': Token'ların gönderileceği adres.
+        // 'amount': Üretilecek token miktarı.
+
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::Mint(to, amount));
+    }
+
+    // 'set_admin' fonksiyonu, kontratın yöneticisini 'new_admin' olarak değiştirir.
+    // Sadece mevcut yönetici tarafından çağrılabilir.
+    pub fn set_admin(e: Env, new_admin: Address) {
+        // 'e': Çalışma ortamı.
+        // 'new_admin': Yeni yönetici olacak adres.
+
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        apply_admin_op(&e, admin, AdminOp::SetAdmin(new_admin)); // Yeni yöneticiyi yaz ve standart 'set_admin' olayını yayınla.
+    }
+
+    // 'upgrade' fonksiyonu, kontratın kodunu daha önce yüklenmiş 'new_wasm_hash' özetli Wasm koduyla değiştirir.
+    // Kontratın adresi ve depolaması korunur; yeni kod depolama düzenini değiştiriyorsa ardından 'migrate' çağrılmalıdır.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn upgrade(e: Env, new_wasm_hash: BytesN<32>) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        apply_admin_op(&e, admin, AdminOp::Upgrade(new_wasm_hash));
+    }
+
+    // 'migrate' fonksiyonu, yükseltmeden sonra verilen hesapların depolamasını bu kodun düzenine taşır.
+    // Hesaplar zincir dışında olaylardan derlenip sayfa sayfa verilir; geçiş tekrar çalıştırılabilir ve kaldığı
+    // yerden sürdürülebilir. Depolama zaten güncelse program panikler. Sadece yönetici tarafından çağrılabilir.
+    pub fn migrate(e: Env, accounts: Vec<Address>) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::Migrate(accounts));
+    }
+
+    // 'finish_migration' fonksiyonu, tüm hesaplar 'migrate' ile taşındıktan sonra yeni depolama sürümünü yazar.
+    // Geçiş bitene kadar ödül dağıtımı kapalıdır. Sadece yönetici tarafından çağrılabilir.
+    pub fn finish_migration(e: Env) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::FinishMigration);
+    }
+
+    // 'version' fonksiyonu, kontratın depolama düzeninin sürümünü döndürür.
+    pub fn version(e: Env) -> u32 {
+        read_version(&e)
+    }
+
+    // 'set_timelock_delay' fonksiyonu, zaman kilitli işlemlerin planlanması ile yürütülmesi arasındaki en az
+    // defter sayısını ayarlar ve böylece zaman kilidini açar. Zaman kilidi açıldıktan sonra bu değişiklik de
+    // 'schedule' ile planlanmalıdır. Sadece yönetici tarafından çağrılabilir.
+    pub fn set_timelock_delay(e: Env, delay: u32) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        apply_admin_op(&e, admin, AdminOp::SetTimelockDelay(delay));
+    }
+
+    // 'timelock_delay' fonksiyonu, zaman kilidinin en az bekleme süresini (defter sayısı) döndürür; 0 ise kapalıdır.
+    pub fn timelock_delay(e: Env) -> u32 {
+        read_timelock_delay(&e)
+    }
+
+    // 'schedule' fonksiyonu, bir yönetici işlemini 'eta_ledger' defterinde yürütülmek üzere zaman kilidi kuyruğuna ekler.
+    // 'eta_ledger', güncel defterden en az bekleme süresi kadar ileride olmalıdır. Planlanan işlemin kimliğini döndürür.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn schedule(e: Env, op: AdminOp, eta_ledger: u32) -> u32 {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        schedule_admin_op(&e, admin, op, eta_ledger)
+    }
+
+    // 'execute' fonksiyonu, yürütme defterine ulaşılmış planlanmış bir işlemi uygular ve kuyruktan çıkarır.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn execute(e: Env, op_id: u32) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::ExecuteScheduled(op_id));
+    }
+
+    // 'cancel' fonksiyonu, planlanmış bir işlemi yürütülmeden kuyruktan çıkarır.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn cancel(e: Env, op_id: u32) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::CancelScheduled(op_id));
+    }
+
+    // 'pending_ops' fonksiyonu, zaman kilidi kuyruğunda bekleyen tüm işlemleri döndürür.
+    pub fn pending_ops(e: Env) -> Vec<ScheduledOp> {
+        read_pending_ops(&e)
+    }
+
+    // 'set_admin_council' fonksiyonu, tek yöneticinin yerine kontrat üzerinde tutulan bir M-of-N yönetici konseyi
+    // ('members', 'threshold') ayarlar. Konsey ayarlandıktan sonra tek yöneticili giriş noktaları kapanır ve yönetici
+    // işlemleri 'council_execute' veya konsey önerileriyle yürütülür. Konsey, 'AdminOp::SetAdmin' ile yeniden tek bir
+    // yöneticiye devredilebilir. Sadece yönetici tarafından çağrılabilir.
+    pub fn set_admin_council(e: Env, members: Vec<Address>, threshold: u32) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        apply_admin_op(&e, admin, AdminOp::SetAdminCouncil(members, threshold));
+    }
+
+    // 'renounce_admin' fonksiyonu, yöneticiyi kalıcı olarak bırakır: yönetici kaydı silinir, varsa yönetici konseyi
+    // kaldırılır ve bekleyen planlanmış işlemler temizlenir. Bundan sonra basım, dondurma ve diğer tüm yönetici
+    // işlemleri açık bir hatayla reddedilir ve kontrat yeniden kurulamaz. Sadece yönetici tarafından çağrılabilir.
+    pub fn renounce_admin(e: Env) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        apply_admin_op(&e, admin, AdminOp::RenounceAdmin);
+    }
+
+    // 'admin' fonksiyonu, yönetici adresini döndürür. Yönetici bırakılmışsa (token sahipsizse) 'None' döner;
+    // yönetici konseyi ayarlıysa kontratın kendi adresi döner.
+    pub fn admin(e: Env) -> Option<Address> {
+        try_read_administrator(&e)
+    }
+
+    // 'admin_council' fonksiyonu, yönetici konseyini (ayarlanmışsa) döndürür.
+    pub fn admin_council(e: Env) -> Option<AdminCouncil> {
+        read_admin_council(&e)
+    }
+
+    // 'council_execute' fonksiyonu, en az eşik kadar konsey üyesinin aynı işlemde imzaladığı bir yönetici işlemini
+    // yürütür. Zaman kilidi etkinse zaman kilitli işlemler kuyruğa eklenir. 'signers' imzalayan üyelerin listesidir.
+    pub fn council_execute(e: Env, signers: Vec<Address>, op: AdminOp) {
+        require_council(&e, &signers); // İmzacıların yeterli sayıda farklı konsey üyesi olduğunu doğrula ve imzalarını zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        dispatch_council_op(&e, op);
+    }
+
+    // 'council_propose' fonksiyonu, konsey üyelerinin farklı zamanlarda onaylayabileceği bir yönetici işlemi önerir.
+    // Önerenin onayı da sayılır. Önerinin kimliğini döndürür. Sadece konsey üyeleri çağırabilir.
+    pub fn council_propose(e: Env, member: Address, op: AdminOp) -> u32 {
+        require_member(&e, &member); // Çağıranın konsey üyesi olduğunu doğrula ve imzasını zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let mut approvals = Vec::new(&e);
+        approvals.push_back(member.clone());
+        let id = next_proposal_id(&e);
+        write_proposal(
+            &e,
+            &Proposal {
+                id,
+                op: op.clone(),
+                approvals,
+            },
+        );
+
+        // Önerilen işlemi içeren bir 'council_propose' olayı yayınla.
+        e.events().publish(("council_propose", member, id), op);
+        id
+    }
+
+    // 'council_approve' fonksiyonu, bekleyen bir öneriye konsey üyesinin onayını ekler.
+    // Sadece konsey üyeleri çağırabilir.
+    pub fn council_approve(e: Env, member: Address, proposal_id: u32) {
+        require_member(&e, &member); // Çağıranın konsey üyesi olduğunu doğrula ve imzasını zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let mut proposal = read_proposal(&e, proposal_id);
+        if proposal.approvals.contains(&member) {
+            panic!("member has already approved");
+        }
+        proposal.approvals.push_back(member.clone());
+        write_proposal(&e, &proposal);
+
+        // Onaylayan üyeyi ve öneriyi içeren bir 'council_approve' olayı yayınla.
+        e.events()
+            .publish(("council_approve", member, proposal_id), proposal.approvals.len());
+    }
+
+    // 'execute_proposal' fonksiyonu, güncel konsey üyelerinden eşik kadar onay almış bir öneriyi yürütür ve siler.
+    // Zaman kilidi etkinse zaman kilitli işlemler kuyruğa eklenir. Onay eşiğine ulaşıldığında herkes çağırabilir.
+    pub fn execute_proposal(e: Env, proposal_id: u32) {
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let council = read_admin_council(&e).unwrap_or_else(|| panic!("admin council is not set"));
+        let proposal = read_proposal(&e, proposal_id);
+        if count_approvals(&council, &proposal) < council.threshold {
+            panic!("proposal does not have enough approvals");
+        }
+        remove_proposal(&e, proposal_id);
+
+        // Yürütülen öneriyi bildiren bir 'execute_proposal' olayı yayınla, ardından işlemi yürüt.
+        e.events()
+            .publish(("execute_proposal", proposal_id), proposal.op.clone());
+        dispatch_council_op(&e, proposal.op);
+    }
+
+    // 'proposal' fonksiyonu, verilen kimliğe sahip bekleyen konsey önerisini döndürür.
+    pub fn proposal(e: Env, proposal_id: u32) -> Proposal {
+        read_proposal(&e, proposal_id)
+    }
+
+    // 'freeze_account' fonksiyonu, belirtilen 'account' adresini bir neden koduyla dondurur.
+    // Dondurulmuş hesaplar token transfer edemez veya yakamaz.
+    // 'unfreeze_at_ledger' verilirse dondurma o defterde kendiliğinden kalkar.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn freeze_account(e: Env, account: Address, reason: u32, unfreeze_at_ledger: Option<u32>) {
+        // 'e': Çalışma ortamı.
+        // 'account': Dondurulacak hesap adresi.
+        // 'reason': Dondurmanın neden kodu.
+        // 'unfreeze_at_ledger': Dondurmanın kendiliğinden kalkacağı defter (isteğe bağlı).
+
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::FreezeAccount(account, reason, unfreeze_at_ledger));
+    }
+
+    // 'unfreeze_account' fonksiyonu, belirtilen 'account' adresinin dondurulmasını kaldırır.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn unfreeze_account(e: Env, account: Address) {
+        // 'e': Çalışma ortamı.
+        // 'account': Dondurulması kaldırılacak hesap adresi.
+
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::UnfreezeAccount(account));
+    }
+
+    // 'freeze_accounts' fonksiyonu, yaptırım listesi güncellemeleri gibi durumlar için birden çok hesabı
+    // tek bir yönetici imzasıyla aynı neden kodu ve çözülme defteriyle dondurur.
+    // Zaten dondurulmuş hesaplar atlanır; dondurulan her hesap için ayrı bir 'freeze_account' olayı yayınlanır.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn freeze_accounts(e: Env, accounts: Vec<Address>, reason: u32, unfreeze_at_ledger: Option<u32>) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::FreezeAccounts(accounts, reason, unfreeze_at_ledger));
+    }
+
+    // 'unfreeze_accounts' fonksiyonu, birden çok hesabın dondurulmasını tek bir yönetici imzasıyla kaldırır.
+    // Dondurulmamış hesaplar atlanır; çözülen her hesap için ayrı bir 'unfreeze_account' olayı yayınlanır.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn unfreeze_accounts(e: Env, accounts: Vec<Address>) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::UnfreezeAccounts(accounts));
+    }
+
+    // 'freeze_info' fonksiyonu, hesabın etkin dondurma kaydını (neden, yetkili, defterler) döndürür.
+    // Hesap dondurulmamışsa veya dondurmanın süresi dolmuşsa 'None' döner.
+    pub fn freeze_info(e: Env, account: Address) -> Option<FreezeInfo> {
+        read_freeze(&e, &account).filter(|info| is_freeze_active(&e, info))
+    }
+
+    // 'is_frozen' fonksiyonu, hesabın şu anda dondurulmuş olup olmadığını döndürür.
+    pub fn is_frozen(e: Env, account: Address) -> bool {
+        is_account_frozen(&e, &account)
+    }
+
+    // 'freeze_amount' fonksiyonu, 'account' hesabının bakiyesinde 'amount' kadarını dondurur (kısmi dondurma).
+    // Dondurulan miktarlar birikir; hesap, bakiyesinin dondurulmuş kısmını harcayamaz ama kalanını kullanabilir.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn freeze_amount(e: Env, account: Address, amount: i128) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::FreezeAmount(account, amount));
+    }
+
+    // 'unfreeze_amount' fonksiyonu, 'account' hesabının dondurulmuş miktarından 'amount' kadarını çözer.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn unfreeze_amount(e: Env, account: Address, amount: i128) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::UnfreezeAmount(account, amount));
+    }
+
+    // 'frozen_amount' fonksiyonu, 'account' hesabının bakiyesinde kısmen dondurulmuş miktarı döndürür.
+    pub fn frozen_amount(e: Env, account: Address) -> i128 {
+        read_frozen_amount(&e, &account)
+    }
+
+    // 'force_transfer' fonksiyonu, zincir dışı doğrulamadan sonra (örneğin anahtarını kaybeden bir hesabın kurtarılması)
+    // yöneticinin 'from' adresinden 'to' adresine 'amount' kadar token taşımasını sağlar. 'from' hesabının dondurulmuş
+    // olması işlemi engellemez; kısmen dondurulmuş miktar tokenlarla birlikte 'to' adresine taşınır. Alıcı yine de
+    // izin listesi ve kimlik beyanı kurallarına uymalıdır. Denetçilerin kurtarmaları olağan transferlerden ayırabilmesi
+    // için standart 'transfer' olayı yerine neden kodunu içeren ayrı bir 'force_transfer' olayı yayınlanır.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn force_transfer(e: Env, from: Address, to: Address, amount: i128, reason: u32) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::ForceTransfer(from, to, amount, reason));
+    }
+
+    // 'set_treasury' fonksiyonu, token ihraççısının hazine (treasury) adresini ayarlar.
+    // Hazine, en fazla sahip sayısı ve hesap başına bakiye sınırlarından muaftır.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn set_treasury(e: Env, treasury: Address) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::SetTreasury(treasury));
+    }
+
+    // 'treasury' fonksiyonu, hazine (treasury) adresini (ayarlanmışsa) döndürür.
+    pub fn treasury(e: Env) -> Option<Address> {
+        read_treasury(&e)
+    }
+
+    // 'set_max_holders' fonksiyonu, izin verilen en fazla token sahibi sayısını ayarlar; 'None' sınırı kaldırır.
+    // Sınır, mevcut sahipleri etkilemez; yalnızca yeni sahip olacak hesapların token almasını engeller.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn set_max_holders(e: Env, max_holders: Option<u32>) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        apply_admin_op(&e, admin, AdminOp::SetMaxHolders(max_holders));
+    }
+
+    // 'max_holders' fonksiyonu, en fazla token sahibi sayısını (ayarlanmışsa) döndürür.
+    pub fn max_holders(e: Env) -> Option<u32> {
+        read_max_holders(&e)
+    }
+
+    // 'set_max_balance_per_account' fonksiyonu, tek bir hesabın tutabileceği en fazla bakiyeyi ayarlar;
+    // 'None' sınırı kaldırır. Sadece yönetici tarafından çağrılabilir.
+    pub fn set_max_balance_per_account(e: Env, max_balance: Option<i128>) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        apply_admin_op(&e, admin, AdminOp::SetMaxBalancePerAccount(max_balance));
+    }
+
+    // 'max_balance_per_account' fonksiyonu, hesap başına en fazla bakiyeyi (ayarlanmışsa) döndürür.
+    pub fn max_balance_per_account(e: Env) -> Option<i128> {
+        read_max_balance_per_account(&e)
+    }
+
+    // 'grant_role' fonksiyonu, 'account' adresine verilen rolü (örneğin KYC operatörü) atar.
+    // Zaman kilidi açıkken 'schedule' ile planlanmalıdır. Sadece yönetici tarafından çağrılabilir.
+    pub fn grant_role(e: Env, role: Role, account: Address) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        apply_admin_op(&e, admin, AdminOp::GrantRole(role, account));
+    }
+
+    // 'revoke_role' fonksiyonu, 'account' adresinden verilen rolü geri alır.
+    // Sadece yönetici tarafından çağrılabilir.
+    pub fn revoke_role(e: Env, role: Role, account: Address) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::RevokeRole(role, account));
+    }
+
+    // 'has_role' fonksiyonu, 'account' adresinin verilen role sahip olup olmadığını döndürür.
+    pub fn has_role(e: Env, role: Role, account: Address) -> bool {
+        has_role(&e, role, &account)
+    }
+
+    // 'allow' fonksiyonu, 'account' adresini isteğe bağlı bir son geçerlilik defteriyle izin listesine ekler.
+    // Yönetici veya KYC operatörü rolüne sahip bir adres ('operator') tarafından çağrılabilir.
+    pub fn allow(e: Env, operator: Address, account: Address, expiration_ledger: Option<u32>) {
+        require_admin_or_role(&e, Role::KycOperator, &operator); // Çağıranın yetkili olduğunu doğrula.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_allowed(&e, &account, expiration_ledger);
+        e.events()
+            .publish(("allow", operator, account), expiration_ledger);
+    }
+
+    // 'disallow' fonksiyonu, 'account' adresini izin listesinden çıkarır.
+    // Yönetici veya KYC operatörü rolüne sahip bir adres ('operator') tarafından çağrılabilir.
+    pub fn disallow(e: Env, operator: Address, account: Address) {
+        require_admin_or_role(&e, Role::KycOperator, &operator); // Çağıranın yetkili olduğunu doğrula.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        remove_allowed(&e, &account);
+        emit_custom_event(&e, "disallow", operator, account, ());
+    }
+
+    // 'is_allowed' fonksiyonu, 'account' adresinin token tutup alabilip alamayacağını döndürür.
+    // Açık modda her hesap için 'true' döner.
+    pub fn is_allowed(e: Env, account: Address) -> bool {
+        is_allowed(&e, &account)
+    }
+
+    // 'access_mode' fonksiyonu, token'ın erişim modunu döndürür.
+    pub fn access_mode(e: Env) -> AccessMode {
+        read_access_mode(&e)
+    }
+
+    // 'set_claim' fonksiyonu, 'account' hesabına verilen konuda bir kimlik beyanı (claim) yazar.
+    // Aynı konudaki önceki beyanın yerini alır. Yönetici veya beyan yayıncısı rolündeki bir adres ('issuer') çağırabilir.
+    pub fn set_claim(
+        e: Env,
+        issuer: Address,
+        account: Address,
+        topic: ClaimTopic,
+        value: u32,
+        expiration_ledger: Option<u32>,
+    ) {
+        require_admin_or_role(&e, Role::ClaimIssuer, &issuer); // Çağıranın yetkili olduğunu doğrula.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_claim(
+            &e,
+            &account,
+            Claim {
+                topic,
+                value,
+                issuer: issuer.clone(),
+                expiration_ledger,
+            },
+        );
+        e.events()
+            .publish(("set_claim", issuer, account), (topic, value, expiration_ledger));
+    }
+
+    // 'revoke_claim' fonksiyonu, 'account' hesabının verilen konudaki beyanını geri alır.
+    // Beyanı yalnızca onu veren yayıncı veya yönetici geri alabilir.
+    pub fn revoke_claim(e: Env, issuer: Address, account: Address, topic: ClaimTopic) {
+        require_admin_or_role(&e, Role::ClaimIssuer, &issuer); // Çağıranın yetkili olduğunu doğrula.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let claim = remove_claim(&e, &account, topic);
+        if claim.issuer != issuer && try_read_administrator(&e).as_ref() != Some(&issuer) {
+            panic!("only the claim issuer or admin can revoke a claim");
+        }
+        e.events().publish(("revoke_claim", issuer, account), topic);
+    }
+
+    // 'claims_of' fonksiyonu, 'account' hesabının geçerli (süresi dolmamış ve yetkili yayıncılı) beyanlarını döndürür.
+    pub fn claims_of(e: Env, account: Address) -> Vec<Claim> {
+        read_valid_claims(&e, &account)
+    }
+
+    // 'set_required_claims' fonksiyonu, 'transfer' ve 'transfer_from' işlemlerinde alıcının geçerli bir beyana
+    // sahip olması gereken konuları ayarlar (örneğin nitelikli yatırımcı). Sadece yönetici tarafından çağrılabilir.
+    pub fn set_required_claims(e: Env, topics: Vec<ClaimTopic>) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::SetRequiredClaims(topics));
+    }
+
+    // 'required_claims' fonksiyonu, transferlerde alıcının sahip olması gereken beyan konularını döndürür.
+    pub fn required_claims(e: Env) -> Vec<ClaimTopic> {
+        read_required_claims(&e)
+    }
+
+    // 'set_compliance' fonksiyonu, transfer kurallarını denetleyecek harici uyumluluk kontratını kaydeder;
+    // 'None' kaydı kaldırır. Kayıtlı kontratın 'can_transfer' fonksiyonu 'transfer', 'transfer_from',
+    // 'mint', 'burn' ve 'burn_from' öncesinde çağrılır. Sadece yönetici tarafından çağrılabilir.
+    pub fn set_compliance(e: Env, compliance: Option<Address>) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        apply_admin_op(&e, admin, AdminOp::SetCompliance(compliance));
+    }
+
+    // 'compliance' fonksiyonu, kayıtlı uyumluluk kontratının adresini (varsa) döndürür.
+    pub fn compliance(e: Env) -> Option<Address> {
+        read_compliance(&e)
+    }
+
+    // 'create_stream' fonksiyonu, 'from' adresinden 'to' adresine defter başına 'rate_per_ledger' hızla akan
+    // bir ödeme akışı oluşturur. 'deposit' miktarı 'from' bakiyesinden kontratın kendi bakiyesine (emanete) aktarılır;
+    // çekimler ve iptal bu emanetten ödenir. Oluşturulan akışın kimliğini döndürür.
+    pub fn create_stream(
+        e: Env,
+        from: Address,
+        to: Address,
+        rate_per_ledger: i128,
+        start_ledger: u32,
+        stop_ledger: u32,
+        deposit: i128,
+    ) -> u32 {
+        from.require_auth(); // Akışı fonlayan 'from' adresinin işlemi imzalamasını zorunlu kıl.
+
+        if rate_per_ledger <= 0 {
+            panic!("rate_per_ledger must be positive");
+        }
+        if start_ledger < e.ledger().sequence() {
+            panic!("start_ledger is less than ledger seq");
+        }
+        if stop_ledger <= start_ledger {
+            panic!("stop_ledger must be greater than start_ledger");
+        }
+        // Emanete alınan miktar, akışın tamamında akacak miktara tam olarak eşit olmalıdır.
+        // Böylece akış sonunda ne eksik ne fazla token kalır.
+        if rate_per_ledger.checked_mul(i128::from(stop_ledger - start_ledger)) != Some(deposit) {
+            panic!("deposit must equal rate_per_ledger * (stop_ledger - start_ledger)");
+        }
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        // Göndericinin ('from') hesabı dondurulmuş mu kontrol et.
+        if is_account_frozen(&e, &from) {
+            panic!("Hesap dondurulmuş ve token transfer edilemez");
+        }
+        check_allowed(&e, &from); // İzin listesi modunda her iki tarafın da izinli olduğunu kontrol et.
+        check_allowed(&e, &to);
+        check_required_claims(&e, &to); // Alıcının gerekli kimlik beyanlarına sahip olduğunu kontrol et.
+        check_compliance(&e, &from, &to, deposit); // Uyumluluk kontratı akışın alıcısına transfere izin veriyor mu kontrol et.
+
+        let contract = e.current_contract_address();
+        move_to_escrow(&e, from.clone(), deposit); // Emanete alınacak miktarı 'from' bakiyesinden kontrata aktar.
+        write_escrow_total(&e, read_escrow_total(&e) + deposit);
+        notify_compliance(&e, &from, &contract, deposit); // Uyumluluk kontratına emanete alınan miktarı bildir.
+
+        let id = next_stream_id(&e); // Yeni akış için kimlik üret.
+        write_stream(
+            &e,
+            id,
+            &Stream {
+                sender: from.clone(),
+                recipient: to.clone(),
+                rate_per_ledger,
+                start_ledger,
+                stop_ledger,
+                deposit,
+                withdrawn: 0,
+            },
+        );
+
+        // Akışın oluşturulduğunu bildiren bir olay ve emanet için standart 'transfer' olayını yayınla.
+        e.events()
+            .publish(("create_stream", from.clone(), to), (id, rate_per_ledger, deposit));
+        TokenUtils::new(&e).events().transfer(from, contract, deposit);
+        id
+    }
+
+    // 'withdraw_from_stream' fonksiyonu, akışın alıcısının tahakkuk etmiş tutardan 'amount' kadarını çekmesini sağlar.
+    // Akışın tamamı çekildiğinde akış kaydı silinir.
+    pub fn withdraw_from_stream(e: Env, id: u32, amount: i128) {
+        check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
+
+        let mut stream = read_stream(&e, id); // Akış kaydını oku.
+        stream.recipient.require_auth();      // Yalnızca alıcı kendi akışından çekim yapabilir.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        if amount > withdrawable_amount(&e, &stream) {
+            panic!("amount exceeds withdrawable stream balance");
+        }
+        check_allowed(&e, &stream.recipient); // İzin listesi modunda alıcının hâlâ izinli olduğunu kontrol et.
+        check_required_claims(&e, &stream.recipient); // Alıcının gerekli kimlik beyanlarına hâlâ sahip olduğunu kontrol et.
+        let contract = e.current_contract_address();
+        check_compliance(&e, &contract, &stream.recipient, amount); // Uyumluluk kontratı emanetten çıkışa izin veriyor mu kontrol et.
+
+        stream.withdrawn += amount; // Çekilen toplam miktarı güncelle.
+        if stream.withdrawn == stream.deposit {
+            remove_stream(&e, id); // Emanetteki tutarın tamamı çekildiyse akış kapanır.
+        } else {
+            write_stream(&e, id, &stream);
+        }
+
+        write_escrow_total(&e, read_escrow_total(&e) - amount);
+        move_from_escrow(&e, stream.recipient.clone(), amount); // Çekilen miktarı emanetten alıcının bakiyesine aktar.
+        notify_compliance(&e, &contract, &stream.recipient, amount); // Uyumluluk kontratına emanetten çıkışı bildir.
+        e.events()
+            .publish(("withdraw_from_stream", stream.recipient.clone()), (id, amount));
+        TokenUtils::new(&e)
+            .events()
+            .transfer(contract, stream.recipient, amount); // Emanetten çıkışı bildiren standart 'transfer' olayı.
+    }
+
+    // 'cancel_stream' fonksiyonu, akışın gönderen veya alıcısı tarafından iptal edilmesini sağlar.
+    // Tahakkuk etmiş ama çekilmemiş kısım alıcıya, henüz akmamış kısım göndericiye orantılı olarak ödenir.
+    pub fn cancel_stream(e: Env, caller: Address, id: u32) {
+        let stream = read_stream(&e, id); // Akış kaydını oku.
+        if caller != stream.sender && caller != stream.recipient {
+            panic!("only the sender or recipient can cancel the stream");
+        }
+        caller.require_auth(); // İptal eden tarafın işlemi imzalamasını zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let streamed = streamed_amount(&e, &stream);
+        let recipient_amount = streamed - stream.withdrawn; // Alıcıya kalan tahakkuk etmiş tutar.
+        let sender_amount = stream.deposit - streamed;      // Göndericiye iade edilecek akmamış tutar.
+
+        // Emanetten her iki tarafa yapılacak ödemeler de uyumluluk kurallarından geçer.
+        let contract = e.current_contract_address();
+        // Göndericiye iade, token'ları asıl sahibine döndürdüğü için beyan kontrolü yalnızca alıcıya uygulanır.
+        if recipient_amount > 0 {
+            check_allowed(&e, &stream.recipient);
+            check_required_claims(&e, &stream.recipient);
+            check_compliance(&e, &contract, &stream.recipient, recipient_amount);
+        }
+        if sender_amount > 0 {
+            check_allowed(&e, &stream.sender); // İzin listesinden çıkarılan göndericiye de iade yapılmaz.
+            check_compliance(&e, &contract, &stream.sender, sender_amount);
+        }
+
+        remove_stream(&e, id); // Akış kaydını sil; emanetteki tutar aşağıda tamamen dağıtılır.
+        write_escrow_total(&e, read_escrow_total(&e) - recipient_amount - sender_amount);
+        if recipient_amount > 0 {
+            move_from_escrow(&e, stream.recipient.clone(), recipient_amount);
+            notify_compliance(&e, &contract, &stream.recipient, recipient_amount);
+            TokenUtils::new(&e)
+                .events()
+                .transfer(contract.clone(), stream.recipient.clone(), recipient_amount);
+        }
+        if sender_amount > 0 {
+            move_from_escrow(&e, stream.sender.clone(), sender_amount);
+            notify_compliance(&e, &contract, &stream.sender, sender_amount);
+            TokenUtils::new(&e)
+                .events()
+                .transfer(contract, stream.sender.clone(), sender_amount);
+        }
+
+        e.events()
+            .publish(("cancel_stream", caller), (id, recipient_amount, sender_amount));
+    }
+
+    // 'stream' fonksiyonu, verilen kimliğe sahip akış kaydını döndürür.
+    pub fn stream(e: Env, id: u32) -> Stream {
+        read_stream(&e, id)
+    }
+
+    // 'hold' fonksiyonu, 'from' hesabının bakiyesinden 'amount' kadarını 'operator' adına rezerve eder.
+    // Rezerve edilen miktar bakiyede kalır ancak 'expiration_ledger' defterine kadar harcanamaz.
+    // Oluşturulan rezervasyonun kimliğini döndürür.
+    pub fn hold(
+        e: Env,
+        from: Address,
+        operator: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> u32 {
+        from.require_auth(); // Bakiyesi rezerve edilecek hesabın işlemi imzalamasını zorunlu kıl.
+
+        check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
+        if expiration_ledger < e.ledger().sequence() {
+            panic!("expiration_ledger is less than ledger seq");
+        }
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        // Dondurulmuş hesaplar bakiyelerini rezerve edemez.
+        if is_account_frozen(&e, &from) {
+            panic!("Hesap dondurulmuş ve token transfer edilemez");
+        }
+
+        // Yalnızca henüz rezerve edilmemiş ve dondurulmamış bakiye yeni bir rezervasyona ayrılabilir.
+        let locked = read_held_total(&e, &from) + read_frozen_amount(&e, &from);
+        if read_balance(&e, from.clone()) - locked < amount {
+            panic!("insufficient unheld balance");
+        }
+
+        let id = next_hold_id(&e); // Yeni rezervasyon için kimlik üret.
+        write_hold(
+            &e,
+            id,
+            &Hold {
+                from: from.clone(),
+                operator: operator.clone(),
+                amount,
+                expiration_ledger,
+            },
+        );
+        add_account_hold(&e, &from, id); // Rezervasyonu hesabın listesine ekle.
+
+        // Rezervasyonun oluşturulduğunu bildiren bir olay yayınla.
+        e.events()
+            .publish(("hold", from, operator), (id, amount, expiration_ledger));
+        id
+    }
+
+    // 'execute_hold' fonksiyonu, operatörün rezervasyondan 'amount' kadarını 'to' adresine tahsil etmesini sağlar.
+    // Kısmi tahsilata izin verilir; kalan miktar rezervasyonda kalmaya devam eder.
+    pub fn execute_hold(e: Env, id: u32, to: Address, amount: i128) {
+        check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
+
+        let mut hold = read_hold(&e, id); // Rezervasyon kaydını oku.
+        hold.operator.require_auth();     // Yalnızca operatör tahsilat yapabilir.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        if !is_hold_active(&e, &hold) {
+            panic!("hold is expired");
+        }
+        if amount > hold.amount {
+            panic!("amount exceeds held amount");
+        }
+        if is_account_frozen(&e, &hold.from) {
+            panic!("Hesap dondurulmuş ve token transfer edilemez");
+        }
+        check_allowed(&e, &hold.from); // İzin listesi modunda her iki tarafın da izinli olduğunu kontrol et.
+        check_allowed(&e, &to);
+        check_required_claims(&e, &to); // Alıcının gerekli kimlik beyanlarına sahip olduğunu kontrol et.
+        check_compliance(&e, &hold.from, &to, amount); // Uyumluluk kontratı tahsilata izin veriyor mu kontrol et.
+
+        // Önce rezervasyonu azaltırız; böylece tahsil edilen kısım 'spend_balance' için serbest kalır.
+        hold.amount -= amount;
+        if hold.amount == 0 {
+            remove_hold(&e, id, &hold);
+        } else {
+            write_hold(&e, id, &hold);
+        }
+
+        spend_balance(&e, hold.from.clone(), amount); // Tahsil edilen miktarı hesabın bakiyesinden düş.
+        receive_balance(&e, to.clone(), amount);      // Tahsil edilen miktarı 'to' adresine ekle.
+        notify_compliance(&e, &hold.from, &to, amount); // Uyumluluk kontratına tahsilatı bildir.
+
+        e.events()
+            .publish(("execute_hold", hold.operator), (id, amount));
+        TokenUtils::new(&e).events().transfer(hold.from, to, amount); // Standart 'transfer' olayını yayınla.
+    }
+
+    // 'release_hold' fonksiyonu, operatörün rezervasyonun kalanını tahsil etmeden serbest bırakmasını sağlar.
+    pub fn release_hold(e: Env, id: u32) {
+        let hold = read_hold(&e, id); // Rezervasyon kaydını oku.
+        hold.operator.require_auth(); // Yalnızca operatör rezervasyonu serbest bırakabilir.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        remove_hold(&e, id, &hold);
+        e.events()
+            .publish(("release_hold", hold.operator), (id, hold.amount));
+    }
+
+    // 'held_balance' fonksiyonu, verilen rezervasyonda tutulan miktarı döndürür.
+    // Serbest bırakılmış, tamamen tahsil edilmiş veya süresi dolmuş rezervasyonlar için 0 döner.
+    pub fn held_balance(e: Env, id: u32) -> i128 {
+        match try_read_hold(&e, id) {
+            Some(hold) if is_hold_active(&e, &hold) => hold.amount,
+            _ => 0,
+        }
+    }
+
+    // 'holder_count' fonksiyonu, bakiyesi sıfırdan büyük olan hesapların (token sahiplerinin) sayısını döndürür.
+    pub fn holder_count(e: Env) -> u32 {
+        read_holder_count(&e)
+    }
+
+    // 'holders' fonksiyonu, token sahiplerini sayfalı olarak döndürür:
+    // 'start' sırasından başlayarak en fazla 'limit' adet adres.
+    pub fn holders(e: Env, start: u32, limit: u32) -> Vec<Address> {
+        read_holders(&e, start, limit)
+    }
+
+    // 'set_guardians' fonksiyonu, 'owner' hesabının sosyal kurtarma koruyucularını, gereken onay eşiğini ve
+    // eşiğe ulaşıldıktan sonraki bekleme süresini (defter sayısı) ayarlar. Boş koruyucu listesi sosyal kurtarmayı
+    // kapatır. Ayarların değiştirilmesi bekleyen kurtarmayı iptal eder. Sadece hesap sahibi çağırabilir.
+    pub fn set_guardians(e: Env, owner: Address, guardians: Vec<Address>, threshold: u32, delay_ledgers: u32) {
+        owner.require_auth(); // Hesap sahibinin işlemi imzalamasını zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        if !guardians.is_empty() && (threshold == 0 || threshold > guardians.len()) {
+            panic!("threshold must be between 1 and the number of guardians");
+        }
+        let mut unique = Vec::new(&e);
+        for guardian in guardians.iter() {
+            if guardian == owner || unique.contains(&guardian) {
+                panic!("guardians must be unique and different from the owner");
+            }
+            unique.push_back(guardian);
+        }
+
+        write_guardians(
+            &e,
+            &owner,
+            &GuardianConfig {
+                guardians,
+                threshold,
+                delay_ledgers,
+            },
+        );
+        remove_recovery(&e, &owner);
 
-#[contract] // Bu struct'ın bir Soroban akıllı kontratı olduğunu belirtir.
-pub struct Token; // 'Token' adlı kontrat yapısı. Bu yapı, kontratın durumunu değil, tipini tanımlar.
-                  // Kontratın durumu 'Env' üzerinden erişilen depolamada tutulur.
+        // Onay eşiğini ve bekleme süresini içeren bir 'set_guardians' olayı yayınla.
+        e.events()
+            .publish(("set_guardians", owner), (threshold, delay_ledgers));
+    }
 
-#[contractimpl] // Bu blok, 'Token' kontratı için fonksiyonları (metodları) içerir.
-impl Token {
-    // 'initialize' fonksiyonu, kontratı ilk kez kurar. Sadece bir kez çağrılabilir.
-    // Yöneticiyi (admin), ondalık sayısını, ismini ve sembolünü ayarlar.
-    pub fn initialize(e: Env, admin: Address, decimal: u32, name: String, symbol: String) {
-        // 'e': Çalışma ortamı.
-        // 'admin': Kontratın yöneticisi olacak adres.
-        // 'decimal': Token'ın ondalık basamak sayısı.
-        // 'name': Token'ın adı.
-        // 'symbol': Token'ın sembolü.
+    // 'guardians' fonksiyonu, 'owner' hesabının koruyucu ayarlarını (varsa) döndürür.
+    pub fn guardians(e: Env, owner: Address) -> Option<GuardianConfig> {
+        read_guardians(&e, &owner)
+    }
+
+    // 'propose_recovery' fonksiyonu, 'owner' hesabının bakiyesini ve harcama izinlerini 'new_owner' adresine
+    // taşıyacak bir kurtarma önerir. Öneren koruyucunun onayı da sayılır. Sadece hesabın koruyucuları çağırabilir.
+    pub fn propose_recovery(e: Env, guardian: Address, owner: Address, new_owner: Address) {
+        let config = require_guardian(&e, &owner, &guardian); // Çağıranın koruyucu olduğunu doğrula ve imzasını zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-        if has_administrator(&e) { // Eğer 'admin' modülündeki 'has_administrator' fonksiyonu true döndürürse (yani yönetici zaten ayarlanmışsa)...
-            panic!("already initialized") // Kontrat zaten başlatılmışsa hata ver.
+        if read_recovery(&e, &owner).is_some() {
+            panic!("recovery already pending");
+        }
+        if new_owner == owner {
+            panic!("new owner must be different from the owner");
         }
-        write_administrator(&e, &admin); // 'admin' modülünü kullanarak yönetici adresini depolamaya yaz.
 
-        if decimal > u8::MAX.into() { // Eğer ondalık sayısı bir u8'e sığmayacak kadar büyükse...
-                                      // u8::MAX, 8-bitlik işaretsiz bir tamsayının alabileceği maksimum değerdir (255).
-                                      // '.into()' ile u32'den u8 karşılaştırması için dönüştürme yapılır.
-            panic!("Decimal must fit in a u8"); // Hata ver. Standart token arayüzü ondalık için u8 bekler.
+        let mut recovery = Recovery {
+            new_owner: new_owner.clone(),
+            approvals: Vec::new(&e),
+            executable_at_ledger: None,
+        };
+        add_approval(&e, &config, &mut recovery, guardian.clone());
+        write_recovery(&e, &owner, &recovery);
+
+        // Öneren koruyucuyu içeren bir 'propose_recovery' olayı yayınla.
+        e.events()
+            .publish(("propose_recovery", owner, new_owner), guardian);
+    }
+
+    // 'approve_recovery' fonksiyonu, 'owner' hesabı için bekleyen kurtarmaya koruyucunun onayını ekler.
+    // Sadece hesabın koruyucuları çağırabilir.
+    pub fn approve_recovery(e: Env, guardian: Address, owner: Address) {
+        let config = require_guardian(&e, &owner, &guardian); // Çağıranın koruyucu olduğunu doğrula ve imzasını zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let mut recovery =
+            read_recovery(&e, &owner).unwrap_or_else(|| panic!("no pending recovery"));
+        add_approval(&e, &config, &mut recovery, guardian.clone());
+        write_recovery(&e, &owner, &recovery);
+
+        // Yürütülebileceği defteri içeren bir 'approve_recovery' olayı yayınla.
+        e.events().publish(
+            ("approve_recovery", owner, guardian),
+            recovery.executable_at_ledger,
+        );
+    }
+
+    // 'execute_recovery' fonksiyonu, onay eşiğine ulaşmış ve bekleme süresi dolmuş kurtarmayı yürütür:
+    // 'owner' hesabının rezerve edilmemiş bakiyesini (kısmen dondurulmuş miktarıyla birlikte) ve geçerli harcama
+    // izinlerini yeni adrese taşır. Dondurulmuş hesaplar kurtarılamaz. Koşullar sağlandığında herkes çağırabilir.
+    pub fn execute_recovery(e: Env, owner: Address) {
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let recovery = read_recovery(&e, &owner).unwrap_or_else(|| panic!("no pending recovery"));
+        match recovery.executable_at_ledger {
+            Some(ledger) if ledger <= e.ledger().sequence() => {}
+            Some(_) => panic!("recovery delay has not passed"),
+            None => panic!("recovery does not have enough approvals"),
         }
 
-        // 'metadata' modülünü kullanarak token'ın meta verilerini (ondalık, isim, sembol) depolamaya yaz.
-        write_metadata(
-            &e,
-            TokenMetadata { // 'TokenMetadata' yapısını oluştur.
-                decimal,
-                name,
-                symbol,
-            },
-        )
+        // Hesabın dondurulmuş olup olmadığını kontrol et; kurtarma dondurmayı aşmak için kullanılamaz.
+        if is_account_frozen(&e, &owner) {
+            panic!("Hesap dondurulmuş ve token transfer edilemez");
+        }
+        let new_owner = recovery.new_owner;
+        check_allowed(&e, &new_owner);         // İzin listesi modunda yeni adresin izinli olduğunu kontrol et.
+        check_required_claims(&e, &new_owner); // Yeni adresin gerekli kimlik beyanlarına sahip olduğunu kontrol et.
+
+        // Rezerve edilmemiş bakiyenin tamamını ve harcama izinlerini taşı.
+        let amount = read_balance(&e, owner.clone()) - read_held_total(&e, &owner);
+        force_move_balance(&e, owner.clone(), new_owner.clone(), amount);
+        notify_compliance(&e, &owner, &new_owner, amount);
+        move_allowances(&e, &owner, &new_owner);
+        remove_recovery(&e, &owner);
+
+        // Taşınan miktarı içeren bir 'execute_recovery' olayı yayınla.
+        e.events()
+            .publish(("execute_recovery", owner, new_owner), amount);
     }
 
-    // 'mint' fonksiyonu, belirli bir 'to' adresine 'amount' kadar yeni token üretir (basar).
-    // Sadece yönetici (admin) tarafından çağrılabilir.
-    pub fn mint(e: Env, to: Address, amount: i128) {
-        // 'e': Çalışma ortamı.
-        // 'to': Token'ların gönderileceği adres.
-        // 'amount': Üretilecek token miktarı.
+    // 'cancel_recovery' fonksiyonu, 'owner' hesabı için bekleyen kurtarmayı iptal eder.
+    // Sadece hesap sahibi çağırabilir; kurtarma yürütülene kadar herhangi bir anda iptal edilebilir.
+    pub fn cancel_recovery(e: Env, owner: Address) {
+        owner.require_auth(); // Hesap sahibinin işlemi imzalamasını zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let recovery = read_recovery(&e, &owner).unwrap_or_else(|| panic!("no pending recovery"));
+        remove_recovery(&e, &owner);
+
+        // İptal edilen önerinin yeni adresini içeren bir 'cancel_recovery' olayı yayınla.
+        e.events()
+            .publish(("cancel_recovery", owner), recovery.new_owner);
+    }
+
+    // 'pending_recovery' fonksiyonu, 'owner' hesabı için bekleyen kurtarmayı (varsa) döndürür.
+    pub fn pending_recovery(e: Env, owner: Address) -> Option<Recovery> {
+        read_recovery(&e, &owner)
+    }
+
+    // 'update_metadata' fonksiyonu, token'ın adını ve sembolünü günceller; ondalık sayısı değiştirilemez.
+    // Yönetici veya meta veri yöneticisi rolüne sahip bir adres ('operator') tarafından çağrılabilir.
+    pub fn update_metadata(e: Env, operator: Address, name: String, symbol: String) {
+        require_admin_or_role(&e, Role::MetadataManager, &operator); // Çağıranın yetkili olduğunu doğrula.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_name_symbol(&e, name.clone(), symbol.clone());
+        e.events()
+            .publish(("metadata_updated", operator), (name, symbol));
+    }
+
+    // 'set_metadata_field' fonksiyonu, genişletilmiş bir meta veri alanını (örneğin 'icon', 'home_domain',
+    // 'description', 'website', 'issuer') ayarlar; boş bir değer alanı siler. Bilinmeyen anahtarlar ve
+    // 'MAX_FIELD_LENGTH' bayttan uzun değerler tipli bir 'MetadataError' ile reddedilir.
+    // Yönetici veya meta veri yöneticisi rolüne sahip bir adres ('operator') tarafından çağrılabilir.
+    pub fn set_metadata_field(e: Env, operator: Address, key: Symbol, value: String) {
+        require_admin_or_role(&e, Role::MetadataManager, &operator); // Çağıranın yetkili olduğunu doğrula.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_metadata_field(&e, key.clone(), &value);
+        e.events()
+            .publish(("metadata_updated", operator, key), value);
+    }
+
+    // 'metadata_field' fonksiyonu, genişletilmiş bir meta veri alanının değerini (ayarlanmışsa) döndürür.
+    pub fn metadata_field(e: Env, key: Symbol) -> Option<String> {
+        read_metadata_field(&e, key)
+    }
+
+    // 'deposit' fonksiyonu, sarmalayıcı modunda 'from' adresinden 'amount' kadar alttaki token'ı kontrata çeker
+    // ve 'from' adresine aynı miktarda sarılmış token basar. Sadece 'from' adresi çağırabilir.
+    pub fn deposit(e: Env, from: Address, amount: i128) {
+        from.require_auth(); // 'from' adresinin işlemi imzalamasını zorunlu kıl.
 
         check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
-        let admin = read_administrator(&e); // Mevcut yönetici adresini oku.
-        admin.require_auth(); // Bu fonksiyonun çağrılabilmesi için yönetici adresinin işlemi imzalamış olmasını zorunlu kıl.
 
-        // Kontrat örneğinin depolamadaki Yaşam Süresini (TTL) uzat.
-        // Bu, kontratın depolama ücretleri nedeniyle silinmesini engeller.
+        // Kontrat örneğinin TTL'sini uzat.
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-        receive_balance(&e, to.clone(), amount); // 'balance' modülünü kullanarak 'to' adresinin bakiyesine 'amount' ekle.
-                                                 // 'to.clone()' ile adresin kopyası kullanılır.
-        TokenUtils::new(&e).events().mint(admin, to, amount); // Standart 'mint' olayını yayınla.
+        let underlying = require_underlying(&e); // Token sarmalayıcı modunda değilse hata ver.
+        check_allowed(&e, &from); // İzin listesi modunda yatıranın izinli olduğunu kontrol et.
+        check_compliance(&e, &e.current_contract_address(), &from, amount); // Uyumluluk kontratı basıma izin veriyor mu kontrol et.
+
+        // Alttaki token'ı önce kontrata çek, sonra sarılmış token'ı bas.
+        token::Client::new(&e, &underlying).transfer(&from, &e.current_contract_address(), &amount);
+        receive_balance(&e, from.clone(), amount); // 'from' adresinin bakiyesine 'amount' ekle.
+        notify_compliance(&e, &e.current_contract_address(), &from, amount); // Uyumluluk kontratına basımı bildir.
+        e.events().publish(("deposit", from.clone()), amount);
+        TokenUtils::new(&e)
+            .events()
+            .mint(e.current_contract_address(), from, amount); // Arz takibi için standart 'mint' olayını yayınla.
     }
 
-    // 'set_admin' fonksiyonu, kontratın yöneticisini 'new_admin' olarak değiştirir.
-    // Sadece mevcut yönetici tarafından çağrılabilir.
-    pub fn set_admin(e: Env, new_admin: Address) {
-        // 'e': Çalışma ortamı.
-        // 'new_admin': Yeni yönetici olacak adres.
+    // 'withdraw' fonksiyonu, sarmalayıcı modunda 'from' adresinin 'amount' kadar sarılmış token'ını yakar ve
+    // aynı miktarda alttaki token'ı 'from' adresine geri öder. Dondurulmuş hesaplar, dondurulmuş miktarlar ve
+    // rezerve edilmiş bakiyeler çekilemez. Sadece 'from' adresi çağırabilir.
+    pub fn withdraw(e: Env, from: Address, amount: i128) {
+        from.require_auth(); // 'from' adresinin işlemi imzalamasını zorunlu kıl.
 
-        let admin = read_administrator(&e); // Mevcut yönetici adresini oku.
-        admin.require_auth(); // Mevcut yöneticinin işlemi imzalamasını zorunlu kıl.
+        check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
 
         // Kontrat örneğinin TTL'sini uzat.
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-        write_administrator(&e, &new_admin); // 'admin' modülünü kullanarak yeni yönetici adresini depolamaya yaz.
-        TokenUtils::new(&e).events().set_admin(admin, new_admin); // Standart 'set_admin' olayını yayınla (eski ve yeni admini bildirir).
+        let underlying = require_underlying(&e); // Token sarmalayıcı modunda değilse hata ver.
+        if is_account_frozen(&e, &from) {
+            panic!("Hesap dondurulmuş ve token çekilemez"); // Dondurulmuşsa hata ver.
+        }
+        check_compliance(&e, &from, &e.current_contract_address(), amount); // Uyumluluk kontratı yakmaya izin veriyor mu kontrol et.
+
+        // Sarılmış token'ı önce yak, sonra alttaki token'ı geri öde.
+        spend_balance(&e, from.clone(), amount); // 'from' adresinin bakiyesini azalt.
+        notify_compliance(&e, &from, &e.current_contract_address(), amount); // Uyumluluk kontratına yakmayı bildir.
+        token::Client::new(&e, &underlying).transfer(&e.current_contract_address(), &from, &amount);
+        e.events().publish(("withdraw", from.clone()), amount);
+        TokenUtils::new(&e).events().burn(from, amount); // Arz takibi için standart 'burn' olayını yayınla.
     }
 
-    // 'freeze_account' fonksiyonu, belirtilen 'account' adresini dondurur.
-    // Dondurulmuş hesaplar token transfer edemez veya yakamaz.
-    // Sadece yönetici tarafından çağrılabilir.
-    pub fn freeze_account(e: Env, account: Address) {
-        // 'e': Çalışma ortamı.
-        // 'account': Dondurulacak hesap adresi.
+    // 'set_flash_loan_config' fonksiyonu, anlık kredi ücretini baz puan (1/10000) cinsinden ve tek bir kredinin
+    // en fazla miktarını ayarlar; en fazla miktar 0 ise anlık krediler kapalıdır. Sadece yönetici tarafından çağrılabilir.
+    pub fn set_flash_loan_config(e: Env, fee_bps: u32, max_amount: i128) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::SetFlashLoanConfig(fee_bps, max_amount));
+    }
 
-        let admin = read_administrator(&e); // Mevcut yönetici adresini oku.
-        admin.require_auth(); // Yöneticinin işlemi imzalamasını zorunlu kıl.
+    // 'max_flash_loan' fonksiyonu, tek bir anlık kredinin en fazla miktarını döndürür.
+    pub fn max_flash_loan(e: Env) -> i128 {
+        read_max_flash_loan(&e)
+    }
+
+    // 'flash_fee' fonksiyonu, anlık kredi ücret oranını (baz puan) ve 'amount' tutarındaki bir kredinin ücretini döndürür.
+    pub fn flash_fee(e: Env, amount: i128) -> (u32, i128) {
+        (read_flash_fee_bps(&e), flash_fee(&e, amount))
+    }
+
+    // 'set_flash_lender' fonksiyonu, anlık kredileri yürütecek kredi kontratını ayarlar; 'None' anlık kredileri kapatır.
+    // Yalnızca kayıtlı kredi kontratı 'flash_mint' ve 'flash_settle' çağırabilir. Zaman kilidi açıkken yeni kontrat
+    // 'schedule' ile planlanmalıdır; kapatmak hemen yapılabilir. Sadece yönetici tarafından çağrılabilir.
+    pub fn set_flash_lender(e: Env, lender: Option<Address>) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
 
         // Kontrat örneğinin TTL'sini uzat.
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-        // Hesabı dondurulmuş olarak işaretlemek için depolamaya yazarız.
-        let key = DataKey::Frozen(account.clone()); // Dondurma durumu için depolama anahtarı.
-        e.storage().instance().set(&key, &true);    // Anahtarın değerini 'true' (dondurulmuş) olarak ayarla.
+        if lender.is_some() {
+            require_no_timelock(&e); // Zaman kilidi etkinse işlem 'schedule' ile planlanmalıdır.
+        }
+        apply_admin_op(&e, admin, AdminOp::SetFlashLender(lender));
+    }
 
-       // Özel bir 'freeze_account' olayı yayınla.
-       emit_custom_event(&e, "freeze_account", admin, account);
+    // 'flash_lender' fonksiyonu, kayıtlı anlık kredi kontratının adresini (varsa) döndürür.
+    pub fn flash_lender(e: Env) -> Option<Address> {
+        read_flash_lender(&e)
     }
 
-    // 'unfreeze_account' fonksiyonu, belirtilen 'account' adresinin dondurulmasını kaldırır.
-    // Sadece yönetici tarafından çağrılabilir.
-    pub fn unfreeze_account(e: Env, account: Address) {
-        // 'e': Çalışma ortamı.
-        // 'account': Dondurulması kaldırılacak hesap adresi.
+    // 'flash_mint' fonksiyonu, 'receiver' kontratına 'amount' kadar anlık kredi basar, krediyi açık olarak kaydeder
+    // ve ücreti döndürür. Yalnızca kayıtlı kredi kontratı çağırabilir; kredi kontratı aynı çağrı içinde
+    // 'flash_settle' ile krediyi kapatmalıdır.
+    pub fn flash_mint(e: Env, receiver: Address, amount: i128) -> i128 {
+        let lender = read_flash_lender(&e).unwrap_or_else(|| panic!("flash lender is not set"));
+        lender.require_auth(); // Kredi kontratının çağrıyı yetkilendirmesini zorunlu kıl.
+
+        check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        if read_underlying(&e).is_some() {
+            // Anlık kredi karşılıksız token basar; sarılmış token'larda 1:1 karşılık bozulur.
+            panic!("minting is disabled for wrapped tokens");
+        }
+        if amount > read_max_flash_loan(&e) {
+            panic!("amount exceeds max flash loan");
+        }
+        if is_account_frozen(&e, &receiver) {
+            panic!("Hesap dondurulmuş ve anlık kredi alamaz"); // Dondurulmuşsa hata ver.
+        }
+        check_allowed(&e, &receiver); // İzin listesi modunda alıcının izinli olduğunu kontrol et.
+
+        let fee = flash_fee(&e, amount);
+        open_flash_loan(&e, &receiver, &FlashLoan { amount, fee }); // Alıcının açık kredisi varsa reddet.
+        receive_balance(&e, receiver.clone(), amount); // Krediyi alıcının bakiyesine bas.
+        e.events()
+            .publish(("flash_loan", lender.clone(), receiver.clone()), (amount, fee));
+        TokenUtils::new(&e).events().mint(lender, receiver, amount); // Standart 'mint' olayını yayınla.
+        fee
+    }
+
+    // 'flash_settle' fonksiyonu, 'receiver' kontratının açık kredisini kapatır: 'amount + fee' tutarını alıcının
+    // kredi kontratına verdiği harcama izniyle geri çeker, anaparayı yakar ve ücreti hazineye aktarır (hazine yoksa
+    // yakılır). Yalnızca kayıtlı kredi kontratı çağırabilir. İzin veya bakiye yetersizse tüm çağrı geri alınır.
+    pub fn flash_settle(e: Env, receiver: Address) {
+        let lender = read_flash_lender(&e).unwrap_or_else(|| panic!("flash lender is not set"));
+        lender.require_auth(); // Kredi kontratının çağrıyı yetkilendirmesini zorunlu kıl.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let FlashLoan { amount, fee } = close_flash_loan(&e, &receiver);
+
+        // Geri ödeme, alıcının kredi kontratına verdiği harcama izniyle (alıcının yetkisiyle) çekilir.
+        spend_allowance(&e, receiver.clone(), lender, amount + fee);
+
+        // Anaparayı ve ücreti geri çek: anapara yakılır, ücret hazineye aktarılır (hazine yoksa yakılır).
+        let fee_to = read_treasury(&e).unwrap_or_else(|| e.current_contract_address());
+        check_compliance(&e, &receiver, &fee_to, fee); // Uyumluluk kontratı ücret aktarımına izin veriyor mu kontrol et.
+        spend_balance(&e, receiver.clone(), amount + fee);
+        if fee_to != e.current_contract_address() {
+            receive_balance(&e, fee_to.clone(), fee);
+        }
+        notify_compliance(&e, &receiver, &fee_to, fee); // Uyumluluk kontratına ücret aktarımını bildir.
+
+        // Anapara için standart 'burn', ücret için hazineye 'transfer' (hazine yoksa 'burn') olayını yayınla.
+        let events = TokenUtils::new(&e).events();
+        events.burn(receiver.clone(), amount);
+        if fee_to != e.current_contract_address() {
+            events.transfer(receiver, fee_to, fee);
+        } else {
+            events.burn(receiver, fee);
+        }
+    }
+
+    // 'set_index' fonksiyonu, pay tabanlı muhasebenin endeksini ('INDEX_SCALE' ölçeğinde, 1.0 = 1_000_000_000)
+    // günceller; tüm bakiyeler pay × endeks olarak yeniden hesaplanır. Faiz işledikçe endeks artırılır.
+    // Sarmalayıcı modunda kapalıdır. Yönetici veya endeks oracle'ı rolüne sahip bir adres ('operator') tarafından
+    // çağrılabilir; yönetici bırakıldıktan sonra endeks değiştirilemez.
+    pub fn set_index(e: Env, operator: Address, index: i128) {
+        require_admin_or_role(&e, Role::IndexOracle, &operator); // Çağıranın yetkili olduğunu doğrula.
+
+        if read_underlying(&e).is_some() {
+            // Endeks değişikliği bakiyeleri ölçekler; sarılmış token'larda 1:1 karşılık bozulur.
+            panic!("index is fixed for wrapped tokens");
+        }
+        if read_escrow_total(&e) > 0 {
+            // Emanet kayıtları token miktarı olarak tutulur; endeks değişirse kontratın bakiyesi onları karşılamaz.
+            panic!("index cannot change while tokens are in escrow");
+        }
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let previous = read_index(&e);
+        write_index(&e, index);
+        e.events()
+            .publish(("set_index", operator), (previous, index));
+    }
+
+    // 'index' fonksiyonu, pay tabanlı muhasebenin güncel endeksini döndürür.
+    pub fn index(e: Env) -> i128 {
+        read_index(&e)
+    }
+
+    // 'shares_of' fonksiyonu, 'id' adresinin payını döndürür.
+    pub fn shares_of(e: Env, id: Address) -> i128 {
+        read_shares(&e, id)
+    }
+
+    // 'total_shares' fonksiyonu, tüm hesapların toplam payını döndürür.
+    pub fn total_shares(e: Env) -> i128 {
+        read_total_shares(&e)
+    }
+
+    // 'underlying' fonksiyonu, sarmalayıcı modunda sarılan alttaki token'ın adresini döndürür; normal token için 'None'.
+    pub fn underlying(e: Env) -> Option<Address> {
+        read_underlying(&e)
+    }
+
+    // 'distribute' fonksiyonu, 'funder' adresinden 'amount' kadar 'reward_token' (bu token veya başka bir SEP-41
+    // token'ı) çekerek kontratta emanete alır ve token sahiplerine paylarıyla orantılı olarak dağıtır. Dağıtım yalnızca
+    // pay başına kümülatif ödülü artırır; maliyeti token sahibi sayısından bağımsızdır. Ödül token'ı yönetici
+    // tarafından 'add_reward_token' ile kaydedilmiş ve miktar sıfırdan büyük olmalıdır. Sadece 'funder' çağırabilir.
+    pub fn distribute(e: Env, funder: Address, reward_token: Address, amount: i128) {
+        funder.require_auth(); // Fon sağlayanın işlemi imzalamasını zorunlu kıl.
+
+        if amount <= 0 {
+            panic!("distribution amount must be positive");
+        }
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        // Geçiş bitmeden toplam pay, taşınmamış hesapların paylarını içermez; dağıtım bu hesapları atlardı.
+        if read_version(&e) < CONTRACT_VERSION {
+            panic!("storage migration is not finished");
+        }
+
+        let contract = e.current_contract_address();
+
+        // Ödülü kontrata çek: bu token için iç transfer, diğer token'lar için token istemcisi kullanılır.
+        if reward_token == contract {
+            if is_account_frozen(&e, &funder) {
+                panic!("Hesap dondurulmuş ve token transfer edilemez"); // Dondurulmuşsa hata ver.
+            }
+            check_allowed(&e, &funder); // İzin listesi modunda fon sağlayanın izinli olduğunu kontrol et.
+            check_compliance(&e, &funder, &contract, amount); // Uyumluluk kontratı transfere izin veriyor mu kontrol et.
+            move_to_escrow(&e, funder.clone(), amount);
+            notify_compliance(&e, &funder, &contract, amount); // Uyumluluk kontratına transferi bildir.
+            TokenUtils::new(&e)
+                .events()
+                .transfer(funder.clone(), contract.clone(), amount); // Standart 'transfer' olayını yayınla.
+        } else {
+            token::Client::new(&e, &reward_token).transfer(&funder, &contract, &amount);
+        }
+
+        // Kontratın kendi emaneti ödül kazanmadığından dağıtıma katılmaz.
+        let eligible = read_total_shares(&e) - read_shares(&e, contract);
+        add_distribution(&e, &reward_token, amount, eligible);
+        e.events()
+            .publish(("distribute", funder, reward_token), amount);
+    }
+
+    // 'claim_rewards' fonksiyonu, 'account' hesabının 'reward_token' cinsinden tahakkuk etmiş ödülünü hesaba öder
+    // ve ödenen miktarı döndürür. Sadece 'account' çağırabilir.
+    pub fn claim_rewards(e: Env, account: Address, reward_token: Address) -> i128 {
+        account.require_auth(); // Hesap sahibinin işlemi imzalamasını zorunlu kıl.
 
-        let admin = read_administrator(&e); // Mevcut yönetici adresini oku.
-        admin.require_auth(); // Yöneticinin işlemi imzalamasını zorunlu kıl.
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let contract = e.current_contract_address();
+        let shares = read_shares(&e, account.clone());
+        let amount = if reward_token == contract {
+            // Ödeme, kontrat bakiyesinin akış ve airdrop emaneti dışındaki kısmıyla sınırlıdır; endeks değişikliği
+            // veya pay yuvarlaması nedeniyle ödenemeyen kalan hesabın kaydında kalır.
+            let available = read_balance(&e, contract.clone()) - read_escrow_total(&e);
+            let amount = take_rewards(&e, &account, &reward_token, shares, available);
+            if amount > 0 {
+                // Emanetten ödeme, transferle aynı kontrollerden geçer.
+                check_allowed(&e, &account);
+                check_required_claims(&e, &account);
+                check_compliance(&e, &contract, &account, amount);
+                move_from_escrow(&e, account.clone(), amount);
+                notify_compliance(&e, &contract, &account, amount);
+                TokenUtils::new(&e)
+                    .events()
+                    .transfer(contract, account.clone(), amount); // Standart 'transfer' olayını yayınla.
+            }
+            amount
+        } else {
+            let amount = take_rewards(&e, &account, &reward_token, shares, i128::MAX);
+            if amount > 0 {
+                token::Client::new(&e, &reward_token).transfer(&contract, &account, &amount);
+            }
+            amount
+        };
+        e.events()
+            .publish(("claim_rewards", account, reward_token), amount);
+        amount
+    }
+
+    // 'pending_rewards' fonksiyonu, 'account' hesabının 'reward_token' cinsinden talep edebileceği ödülü döndürür.
+    pub fn pending_rewards(e: Env, account: Address, reward_token: Address) -> i128 {
+        pending_rewards(&e, &account, &reward_token, read_shares(&e, account.clone()))
+    }
+
+    // 'add_reward_token' fonksiyonu, 'reward_token' adresini dağıtım yapılabilecek ödül token'ı olarak kaydeder.
+    // En fazla 'MAX_REWARD_TOKENS' token kayıtlı olabilir. Sadece yönetici tarafından çağrılabilir.
+    pub fn add_reward_token(e: Env, reward_token: Address) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::AddRewardToken(reward_token));
+    }
+
+    // 'remove_reward_token' fonksiyonu, 'reward_token' adresini kayıttan çıkarır. Token'a artık dağıtım yapılamaz;
+    // daha önce dağıtılmış ödüllerin tamamı, hesabın payı o zamandan beri değişmemiş olsa bile talep edilebilir.
+    // Çıkarılan token yeniden kaydedilemez. Sadece yönetici tarafından çağrılabilir.
+    pub fn remove_reward_token(e: Env, reward_token: Address) {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_admin_op(&e, admin, AdminOp::RemoveRewardToken(reward_token));
+    }
+
+    // 'reward_tokens' fonksiyonu, kayıtlı ödül token'larının listesini döndürür.
+    pub fn reward_tokens(e: Env) -> Vec<Address> {
+        read_reward_tokens(&e)
+    }
+
+    // 'create_airdrop' fonksiyonu, 'root' Merkle köküne sahip ve toplam 'total' token dağıtan bir airdrop oluşturur.
+    // 'total' hazineden kontratın emanetine aktarılır; hak sahipleri 'claim_airdrop' ile paylarını bu emanetten alır.
+    // Talepler 'expiration_ledger' defterine kadar kabul edilir. Oluşturulan airdrop'un kimliğini döndürür.
+    // Sadece yönetici tarafından, hazinenin de imzasıyla çağrılabilir.
+    pub fn create_airdrop(e: Env, root: BytesN<32>, total: i128, expiration_ledger: u32) -> u32 {
+        let admin = require_admin(&e); // Yöneticinin işlemi imzalamasını zorunlu kıl (konsey ayarlıysa kapalıdır).
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
+        let id = read_airdrop_count(&e); // İşlem bu kimliği alacaktır.
+        apply_admin_op(&e, admin, AdminOp::CreateAirdrop(root, total, expiration_ledger));
+        id
+    }
+
+    // 'claim_airdrop' fonksiyonu, 'id' kimlikli airdrop'ta 'account' hesabına tahsis edilen 'amount' miktarını,
+    // 'proof' Merkle kanıtını doğruladıktan sonra emanetten hesaba aktarır. Her yaprak yalnızca bir kez talep edilebilir.
+    // Token'lar yalnızca yapraktaki hesaba gittiği için herkes (örneğin bir aracı) hesap adına çağırabilir. Yönetici
+    // bırakıldıktan sonra da talepler emanetten ödenmeye devam eder.
+    pub fn claim_airdrop(e: Env, id: u32, account: Address, amount: i128, proof: MerkleProof) {
+        check_nonnegative_amount(amount); // Miktarın negatif olmadığını kontrol et.
+
+        // Kontrat örneğinin TTL'sini uzat.
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let mut airdrop = read_airdrop(&e, id);
+        if e.ledger().sequence() > airdrop.expiration_ledger {
+            panic!("airdrop has expired");
+        }
+        if is_leaf_claimed(&e, id, proof.index) {
+            panic!("airdrop already claimed");
+        }
+        let leaf = airdrop_leaf(&e, proof.index, &account, amount);
+        if !verify_proof(&e, &airdrop.root, leaf, &proof) {
+            panic!("invalid merkle proof");
+        }
+        if airdrop.claimed + amount > airdrop.total {
+            panic!("airdrop total exceeded");
+        }
+        let contract = e.current_contract_address();
+        check_allowed(&e, &account); // İzin listesi modunda alıcının izinli olduğunu kontrol et.
+        check_required_claims(&e, &account); // Alıcının gerekli kimlik beyanlarına sahip olduğunu kontrol et.
+        check_compliance(&e, &contract, &account, amount); // Uyumluluk kontratı transfere izin veriyor mu kontrol et.
+
+        set_leaf_claimed(&e, id, proof.index); // Yaprağı talep edilmiş olarak işaretle.
+        airdrop.claimed += amount;
+        write_airdrop(&e, id, &airdrop);
+        write_escrow_total(&e, read_escrow_total(&e) - amount);
+        move_from_escrow(&e, account.clone(), amount); // Emanetten 'account' adresinin bakiyesine 'amount' aktar.
+        notify_compliance(&e, &contract, &account, amount); // Uyumluluk kontratına transferi bildir.
+        e.events()
+            .publish(("claim_airdrop", id, account.clone()), amount);
+        TokenUtils::new(&e)
+            .events()
+            .transfer(contract, account, amount); // Emanetten çıkışı bildiren standart 'transfer' olayı.
+    }
+
+    // 'reclaim_airdrop' fonksiyonu, süresi dolmuş bir airdrop'ta talep edilmemiş kalan miktarı emanetten hazineye aktarır
+    // ve iade edilen miktarı döndürür. Süre dolduktan sonra herkes çağırabilir.
+    pub fn reclaim_airdrop(e: Env, id: u32) -> i128 {
         // Kontrat örneğinin TTL'sini uzat.
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-        // Dondurulmuş durumunu depolamadan kaldırırız.
-        let key = DataKey::Frozen(account.clone()); // Dondurma durumu için depolama anahtarı.
-        e.storage().instance().remove(&key);        // Anahtarı ve değerini depodan sil.
+        let mut airdrop = read_airdrop(&e, id);
+        if e.ledger().sequence() <= airdrop.expiration_ledger {
+            panic!("airdrop has not expired");
+        }
+        let treasury = read_treasury(&e).unwrap_or_else(|| panic!("treasury is not set"));
+
+        let remaining = airdrop.total - airdrop.claimed;
+        if remaining > 0 {
+            let contract = e.current_contract_address();
+            check_compliance(&e, &contract, &treasury, remaining); // Uyumluluk kontratı transfere izin veriyor mu kontrol et.
+            airdrop.claimed = airdrop.total; // Kalan miktar bir daha iade edilemez.
+            write_airdrop(&e, id, &airdrop);
+            write_escrow_total(&e, read_escrow_total(&e) - remaining);
+            move_from_escrow(&e, treasury.clone(), remaining); // Kalan miktarı emanetten hazineye aktar.
+            notify_compliance(&e, &contract, &treasury, remaining); // Uyumluluk kontratına transferi bildir.
+            TokenUtils::new(&e)
+                .events()
+                .transfer(contract, treasury.clone(), remaining); // Emanetten çıkışı bildiren standart 'transfer' olayı.
+        }
+        e.events()
+            .publish(("reclaim_airdrop", id, treasury), remaining);
+        remaining
+    }
+
+    // 'airdrop' fonksiyonu, verilen kimliğe sahip airdrop kaydını döndürür.
+    pub fn airdrop(e: Env, id: u32) -> Airdrop {
+        read_airdrop(&e, id)
+    }
 
-        // Özel bir 'unfreeze_account' olayı yayınla.
-        emit_custom_event(&e, "unfreeze_account", admin, account);
+    // 'is_airdrop_claimed' fonksiyonu, airdrop'un 'index' sıradaki yaprağının talep edilip edilmediğini döndürür.
+    pub fn is_airdrop_claimed(e: Env, id: u32, index: u32) -> bool {
+        is_leaf_claimed(&e, id, index)
     }
 }
 
@@ -228,10 +2061,16 @@ impl token::Interface for Token {
         if is_account_frozen(&e, &from) {
             panic!("Hesap dondurulmuş ve token transfer edilemez"); // Dondurulmuşsa hata ver.
         }
+        check_allowed(&e, &from); // İzin listesi modunda göndericinin izinli olduğunu kontrol et.
+        check_allowed(&e, &to);   // İzin listesi modunda alıcının izinli olduğunu kontrol et.
+        check_required_claims(&e, &to); // Alıcının gerekli kimlik beyanlarına sahip olduğunu kontrol et.
+
+        check_compliance(&e, &from, &to, amount); // Uyumluluk kontratı transfere izin veriyor mu kontrol et.
 
         // Transferi gerçekleştir:
         spend_balance(&e, from.clone(), amount);    // 'from' adresinin bakiyesini azalt.
         receive_balance(&e, to.clone(), amount);    // 'to' adresinin bakiyesini artır.
+        notify_compliance(&e, &from, &to, amount);  // Uyumluluk kontratına transferi bildir.
         TokenUtils::new(&e).events().transfer(from, to, amount); // Standart 'transfer' olayını yayınla.
     }
 
@@ -251,11 +2090,17 @@ impl token::Interface for Token {
         if is_account_frozen(&e, &from) {
             panic!("Hesap dondurulmuş ve token transfer edilemez"); // Dondurulmuşsa hata ver.
         }
+        check_allowed(&e, &from); // İzin listesi modunda göndericinin izinli olduğunu kontrol et.
+        check_allowed(&e, &to);   // İzin listesi modunda alıcının izinli olduğunu kontrol et.
+        check_required_claims(&e, &to); // Alıcının gerekli kimlik beyanlarına sahip olduğunu kontrol et.
+
+        check_compliance(&e, &from, &to, amount); // Uyumluluk kontratı transfere izin veriyor mu kontrol et.
 
         // Transferi gerçekleştir:
         spend_allowance(&e, from.clone(), spender, amount); // 'spender'ın 'from' adına olan harcama iznini azalt.
         spend_balance(&e, from.clone(), amount);            // 'from' adresinin bakiyesini azalt.
         receive_balance(&e, to.clone(), amount);            // 'to' adresinin bakiyesini artır.
+        notify_compliance(&e, &from, &to, amount);          // Uyumluluk kontratına transferi bildir.
         TokenUtils::new(&e).events().transfer(from, to, amount); // Standart 'transfer' olayını yayınla.
     }
 
@@ -270,13 +2115,21 @@ impl token::Interface for Token {
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
+        if read_underlying(&e).is_some() {
+            // Sarılmış token'lar yalnızca 'withdraw' ile yakılır; aksi halde 1:1 karşılık bozulur.
+            panic!("burning is disabled for wrapped tokens");
+        }
+
         // 'from' adresinin hesabı dondurulmuş mu kontrol et.
         if is_account_frozen(&e, &from) {
             panic!("Hesap dondurulmuş ve token yakılamaz"); // Dondurulmuşsa hata ver.
         }
 
+        check_compliance(&e, &from, &e.current_contract_address(), amount); // Uyumluluk kontratı yakmaya izin veriyor mu kontrol et.
+
         // Yakma işlemini gerçekleştir:
         spend_balance(&e, from.clone(), amount); // 'from' adresinin bakiyesini azalt.
+        notify_compliance(&e, &from, &e.current_contract_address(), amount); // Uyumluluk kontratına yakmayı bildir.
         TokenUtils::new(&e).events().burn(from, amount); // Standart 'burn' olayını yayınla.
     }
 
@@ -292,14 +2145,22 @@ impl token::Interface for Token {
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
+        if read_underlying(&e).is_some() {
+            // Sarılmış token'lar yalnızca 'withdraw' ile yakılır; aksi halde 1:1 karşılık bozulur.
+            panic!("burning is disabled for wrapped tokens");
+        }
+
          // 'from' adresinin hesabı dondurulmuş mu kontrol et.
          if is_account_frozen(&e, &from) {
             panic!("Hesap dondurulmuş ve token yakılamaz"); // Dondurulmuşsa hata ver.
         }
 
+        check_compliance(&e, &from, &e.current_contract_address(), amount); // Uyumluluk kontratı yakmaya izin veriyor mu kontrol et.
+
         // Yakma işlemini gerçekleştir:
         spend_allowance(&e, from.clone(), spender, amount); // 'spender'ın 'from' adına olan harcama iznini azalt.
         spend_balance(&e, from.clone(), amount);            // 'from' adresinin bakiyesini azalt.
+        notify_compliance(&e, &from, &e.current_contract_address(), amount); // Uyumluluk kontratına yakmayı bildir.
         TokenUtils::new(&e).events().burn(from, amount);    // Standart 'burn' olayını yayınla.
     }
 
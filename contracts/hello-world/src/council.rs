@@ -0,0 +1,113 @@
+// Bu dosya (council.rs), 'soroban-token-contract' kütüphanesinin 'council' modülünü oluşturur.
+// Bu modül, harici bir hesap kontratı gerektirmeden yöneticinin kontrat üzerinde tutulan bir M-of-N imzacı
+// kümesi (yönetici konseyi) olmasını sağlar. Konsey ayarlandığında tek yöneticili giriş noktaları kapanır;
+// yönetici işlemleri ('AdminOp') ya en az M üyenin aynı işlemde imzasıyla ya da üyelerin farklı zamanlarda
+// onayladığı öneriler (proposal) üzerinden yürütülür. Konsey 'instance', öneriler 'persistent' depolamada saklanır.
+
+use crate::storage_types::{
+    AdminCouncil, DataKey, Proposal, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{Address, Env, Vec};
+
+// 'read_admin_council' fonksiyonu, yönetici konseyini (ayarlanmışsa) okur.
+pub fn read_admin_council(e: &Env) -> Option<AdminCouncil> {
+    e.storage().instance().get(&DataKey::AdminCouncil)
+}
+
+// 'write_admin_council' fonksiyonu, yönetici konseyini yazar. Üye listesi boş olamaz ve eşik
+// 1 ile üye sayısı arasında olmalıdır; üyeler birbirinden farklı olmalıdır.
+pub fn write_admin_council(e: &Env, council: &AdminCouncil) {
+    if council.threshold == 0 || council.threshold > council.members.len() {
+        panic!("threshold must be between 1 and the number of members");
+    }
+    let mut unique = Vec::new(e);
+    for member in council.members.iter() {
+        if unique.contains(&member) {
+            panic!("council members must be unique");
+        }
+        unique.push_back(member);
+    }
+    e.storage().instance().set(&DataKey::AdminCouncil, council);
+}
+
+// 'remove_admin_council' fonksiyonu, yönetici konseyini siler; yönetim yeniden tek bir adrese geçer.
+pub fn remove_admin_council(e: &Env) {
+    e.storage().instance().remove(&DataKey::AdminCouncil);
+}
+
+// 'require_member' fonksiyonu, 'member' adresinin konsey üyesi olduğunu doğrular ve işlemi imzalamasını
+// zorunlu kılar. Konsey yoksa veya adres üye değilse program panikler. Konseyi döndürür.
+pub fn require_member(e: &Env, member: &Address) -> AdminCouncil {
+    let council = read_admin_council(e).unwrap_or_else(|| panic!("admin council is not set"));
+    if !council.members.contains(member) {
+        panic!("caller is not a council member");
+    }
+    member.require_auth();
+    council
+}
+
+// 'require_council' fonksiyonu, 'signers' listesinin en az eşik kadar farklı konsey üyesinden oluştuğunu
+// doğrular ve her birinin işlemi imzalamasını zorunlu kılar; aksi halde program panikler.
+pub fn require_council(e: &Env, signers: &Vec<Address>) {
+    let council = read_admin_council(e).unwrap_or_else(|| panic!("admin council is not set"));
+    let mut approved = Vec::new(e);
+    for signer in signers.iter() {
+        if !council.members.contains(&signer) || approved.contains(&signer) {
+            panic!("signers must be distinct council members");
+        }
+        signer.require_auth();
+        approved.push_back(signer);
+    }
+    if approved.len() < council.threshold {
+        panic!("not enough council signers");
+    }
+}
+
+// 'count_approvals' fonksiyonu, önerinin onaylarından hâlâ konsey üyesi olanların sayısını döndürür.
+// Konseyden çıkarılan üyelerin önceki onayları sayılmaz.
+pub fn count_approvals(council: &AdminCouncil, proposal: &Proposal) -> u32 {
+    proposal
+        .approvals
+        .iter()
+        .filter(|member| council.members.contains(member))
+        .count() as u32
+}
+
+// 'next_proposal_id' fonksiyonu, yeni öneri için bir kimlik üretir ve öneri sayacını artırır.
+pub fn next_proposal_id(e: &Env) -> u32 {
+    let id: u32 = e
+        .storage()
+        .instance()
+        .get(&DataKey::ProposalCount)
+        .unwrap_or(0);
+    e.storage().instance().set(&DataKey::ProposalCount, &(id + 1));
+    id
+}
+
+// 'read_proposal' fonksiyonu, verilen kimliğe sahip öneriyi okur. Öneri yoksa program panikler.
+pub fn read_proposal(e: &Env, id: u32) -> Proposal {
+    let key = DataKey::Proposal(id);
+    let proposal = e
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic!("proposal not found"));
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    proposal
+}
+
+// 'write_proposal' fonksiyonu, öneriyi depolamaya yazar.
+pub fn write_proposal(e: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    e.storage().persistent().set(&key, proposal);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'remove_proposal' fonksiyonu, yürütülen öneriyi depolamadan siler.
+pub fn remove_proposal(e: &Env, id: u32) {
+    e.storage().persistent().remove(&DataKey::Proposal(id));
+}
@@ -0,0 +1,76 @@
+// Bu dosya (flash.rs), 'soroban-token-contract' kütüphanesinin 'flash' modülünü oluşturur.
+// Bu modül, tek bir çağrı içinde basılıp geri alınan anlık kredileri (flash loan) yönetir.
+// Soroban, bir kontratın çağrı yığınındayken yeniden çağrılmasına (re-entrancy) izin vermez; token alıcıyı kendisi
+// geri çağırsaydı alıcı ödünç aldığı token'ları kullanamazdı. Bu yüzden krediyi yöneticinin kaydettiği ayrı bir
+// kredi kontratı (flash lender) yürütür: token'a 'flash_mint' ile krediyi bastırır, alıcıyı geri çağırır ve
+// 'flash_settle' ile geri ödemeyi çektirir. Geri ödeme, alıcının kredi kontratına önceden verdiği harcama izniyle
+// çekilir. Anapara yakılır; ücret hazineye (treasury) aktarılır, hazine ayarlı değilse yakılır.
+
+use crate::storage_types::{DataKey, FlashLoan};
+use soroban_sdk::{Address, Env};
+
+// 'read_flash_lender' fonksiyonu, kayıtlı anlık kredi kontratının adresini (varsa) okur.
+pub fn read_flash_lender(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::FlashLender)
+}
+
+// 'write_flash_lender' fonksiyonu, anlık kredi kontratının adresini ayarlar; 'None' kaydı kaldırır.
+pub fn write_flash_lender(e: &Env, lender: &Option<Address>) {
+    match lender {
+        Some(lender) => e.storage().instance().set(&DataKey::FlashLender, lender),
+        None => e.storage().instance().remove(&DataKey::FlashLender),
+    }
+}
+
+// 'open_flash_loan' fonksiyonu, alıcının geri ödenmemiş kredisini geçici depolamaya yazar.
+// Alıcının zaten açık bir kredisi varsa program panikler.
+pub fn open_flash_loan(e: &Env, receiver: &Address, loan: &FlashLoan) {
+    let key = DataKey::FlashLoan(receiver.clone());
+    if e.storage().temporary().has(&key) {
+        panic!("flash loan is already outstanding");
+    }
+    e.storage().temporary().set(&key, loan);
+}
+
+// 'close_flash_loan' fonksiyonu, alıcının açık kredisini okur ve siler. Açık kredi yoksa program panikler.
+pub fn close_flash_loan(e: &Env, receiver: &Address) -> FlashLoan {
+    let key = DataKey::FlashLoan(receiver.clone());
+    let loan = e
+        .storage()
+        .temporary()
+        .get(&key)
+        .unwrap_or_else(|| panic!("no outstanding flash loan"));
+    e.storage().temporary().remove(&key);
+    loan
+}
+
+// 'read_flash_fee_bps' fonksiyonu, anlık kredi ücretini baz puan (1/10000) cinsinden okur; varsayılan 0'dır.
+pub fn read_flash_fee_bps(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::FlashFeeBps).unwrap_or(0)
+}
+
+// 'read_max_flash_loan' fonksiyonu, tek bir anlık kredinin en fazla miktarını okur.
+// Varsayılan 0'dır; yani yönetici bir sınır ayarlayana kadar anlık krediler kapalıdır.
+pub fn read_max_flash_loan(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::MaxFlashLoan).unwrap_or(0)
+}
+
+// 'write_flash_loan_config' fonksiyonu, anlık kredi ücretini ve en fazla kredi miktarını ayarlar.
+pub fn write_flash_loan_config(e: &Env, fee_bps: u32, max_amount: i128) {
+    if fee_bps > 10_000 {
+        panic!("flash loan fee must not exceed 10000 basis points");
+    }
+    if max_amount < 0 {
+        panic!("negative max flash loan is not allowed");
+    }
+    e.storage().instance().set(&DataKey::FlashFeeBps, &fee_bps);
+    e.storage().instance().set(&DataKey::MaxFlashLoan, &max_amount);
+}
+
+// 'flash_fee' fonksiyonu, 'amount' tutarındaki bir anlık kredinin ücretini (aşağı yuvarlanmış) hesaplar.
+pub fn flash_fee(e: &Env, amount: i128) -> i128 {
+    amount
+        .checked_mul(read_flash_fee_bps(e).into())
+        .expect("flash loan fee overflow")
+        / 10_000
+}
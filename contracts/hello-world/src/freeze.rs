@@ -0,0 +1,108 @@
+// Bu dosya (freeze.rs), 'soroban-token-contract' kütüphanesinin 'freeze' modülünü oluşturur.
+// Bu modül, hesap dondurma (freeze) kayıtlarını yönetir. Her kayıt, dondurmanın neden (reason) kodunu,
+// dondurmayı yapan yetkiliyi, dondurulduğu defteri ve isteğe bağlı otomatik çözülme defterini tutar;
+// böylece uyumluluk araçları bir hesabın neden ve ne zamana kadar dondurulduğunu görebilir.
+// Otomatik çözülme defteri geçmiş kayıtlar, ayrıca bir işlem gerekmeden çözülmüş sayılır.
+// Tüm hesabı dondurmanın yanında, mahkeme kararları gibi durumlar için yalnızca belirli bir miktar da
+// dondurulabilir (kısmi dondurma); 'spend_balance' bakiyenin dondurulmuş miktarın altına inmesine izin vermez.
+// Dondurma kayıtları sürüm 2'den itibaren 'persistent' depolamada tutulur. Sürüm 1'de 'instance' depolamaya
+// yazılmış ve henüz taşınmamış kayıtlar okunurken hâlâ dikkate alınır. İlk sürümler bu anahtara yalnızca
+// 'true' yazıyordu; böyle bir kayıt, süresiz ve neden kodu olmayan (0) tam dondurma olarak okunur.
+
+use crate::storage_types::{DataKey, FreezeInfo, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{Address, Env, TryFromVal, Val};
+
+// 'read_legacy_freeze' fonksiyonu, 'instance' depolamadaki henüz taşınmamış eski dondurma kaydını (varsa) okur.
+// Kayıt 'FreezeInfo' ya da ilk sürümlerin yazdığı 'bool' olabilir; 'true', yetkilisi bilinmeyen (kontratın kendi
+// adresiyle gösterilen) süresiz bir dondurma kabul edilir.
+fn read_legacy_freeze(e: &Env, key: &DataKey) -> Option<FreezeInfo> {
+    let value = e.storage().instance().get::<DataKey, Val>(key)?;
+    if let Ok(frozen) = bool::try_from_val(e, &value) {
+        return frozen.then(|| FreezeInfo {
+            reason: 0,
+            authority: e.current_contract_address(),
+            frozen_at_ledger: 0,
+            unfreeze_at_ledger: None,
+        });
+    }
+    Some(FreezeInfo::try_from_val(e, &value).unwrap_or_else(|_| panic!("invalid freeze record")))
+}
+
+// 'read_freeze' fonksiyonu, bir hesabın dondurma kaydını (varsa) okur.
+// Süresi dolmuş kayıtlar da döndürülür; etkin olup olmadığını 'is_freeze_active' belirler.
+pub fn read_freeze(e: &Env, account: &Address) -> Option<FreezeInfo> {
+    let key = DataKey::Frozen(account.clone());
+    if let Some(info) = e.storage().persistent().get::<DataKey, FreezeInfo>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        Some(info)
+    } else {
+        read_legacy_freeze(e, &key) // Henüz taşınmamış eski ('instance') kayıt.
+    }
+}
+
+// 'write_freeze' fonksiyonu, bir hesabın dondurma kaydını depolamaya yazar.
+pub fn write_freeze(e: &Env, account: &Address, info: &FreezeInfo) {
+    let key = DataKey::Frozen(account.clone());
+    e.storage().instance().remove(&key); // Varsa eski ('instance') kaydın yerini alır.
+    e.storage().persistent().set(&key, info);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'remove_freeze' fonksiyonu, bir hesabın dondurma kaydını depolamadan siler.
+pub fn remove_freeze(e: &Env, account: &Address) {
+    let key = DataKey::Frozen(account.clone());
+    e.storage().persistent().remove(&key);
+    e.storage().instance().remove(&key);
+}
+
+// 'migrate_freeze' fonksiyonu, bir hesabın 'instance' depolamadaki eski dondurma kaydını (varsa)
+// 'persistent' depolamaya taşır. 'false' değerli eski kayıtlar yalnızca silinir.
+pub fn migrate_freeze(e: &Env, account: &Address) {
+    let key = DataKey::Frozen(account.clone());
+    match read_legacy_freeze(e, &key) {
+        Some(info) => write_freeze(e, account, &info),
+        None => e.storage().instance().remove(&key),
+    }
+}
+
+// 'is_freeze_active' fonksiyonu, dondurma kaydının otomatik çözülme defterine henüz ulaşılmadığını kontrol eder.
+pub fn is_freeze_active(e: &Env, info: &FreezeInfo) -> bool {
+    info.unfreeze_at_ledger
+        .is_none_or(|ledger| e.ledger().sequence() < ledger)
+}
+
+// 'is_account_frozen' fonksiyonu, bir hesabın şu anda dondurulmuş olup olmadığını kontrol eder.
+// Süresi dolmuş dondurmalar kaldırılmış sayılır.
+pub fn is_account_frozen(e: &Env, account: &Address) -> bool {
+    read_freeze(e, account).is_some_and(|info| is_freeze_active(e, &info))
+}
+
+// 'read_frozen_amount' fonksiyonu, bir hesabın bakiyesinde kısmen dondurulmuş miktarı okur.
+pub fn read_frozen_amount(e: &Env, account: &Address) -> i128 {
+    let key = DataKey::FrozenAmount(account.clone());
+    if let Some(amount) = e.storage().persistent().get::<DataKey, i128>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        amount
+    } else {
+        0
+    }
+}
+
+// 'write_frozen_amount' fonksiyonu, bir hesabın kısmen dondurulmuş miktarını yazar; miktar sıfırsa girdiyi siler.
+pub fn write_frozen_amount(e: &Env, account: &Address, amount: i128) {
+    let key = DataKey::FrozenAmount(account.clone());
+    if amount == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &amount);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+}
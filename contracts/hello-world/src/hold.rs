@@ -0,0 +1,116 @@
+// Bu dosya (hold.rs), 'soroban-token-contract' kütüphanesinin 'hold' modülünü oluşturur.
+// Bu modül, kart provizyonlarına benzer bakiye rezervasyonlarını (hold) yönetir.
+// Bir rezervasyon, hesabın bakiyesinin bir kısmını harcanamaz hale getirir; bakiye ('balance') toplam olarak kalır,
+// ancak 'spend_balance' rezerve edilen kısmın altına inilmesine izin vermez.
+// Rezervasyonlar operatör tarafından kısmen veya tamamen tahsil edilebilir, serbest bırakılabilir
+// ve son geçerlilik defterinden sonra kendiliğinden serbest kalır.
+
+use crate::storage_types::{DataKey, Hold, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{Address, Env, Vec};
+
+// 'next_hold_id' fonksiyonu, yeni bir rezervasyon için benzersiz bir kimlik üretir ve sayacı bir artırır.
+pub fn next_hold_id(e: &Env) -> u32 {
+    let key = DataKey::HoldCount;
+    let id = e.storage().instance().get::<_, u32>(&key).unwrap_or(0);
+    e.storage().instance().set(&key, &(id + 1));
+    id
+}
+
+// 'is_hold_active' fonksiyonu, rezervasyonun süresinin dolup dolmadığını kontrol eder.
+// Süresi dolmuş rezervasyonlar otomatik olarak serbest bırakılmış sayılır.
+pub fn is_hold_active(e: &Env, hold: &Hold) -> bool {
+    hold.expiration_ledger >= e.ledger().sequence()
+}
+
+// 'try_read_hold' fonksiyonu, verilen kimliğe sahip rezervasyon kaydını (varsa) okur.
+pub fn try_read_hold(e: &Env, id: u32) -> Option<Hold> {
+    let key = DataKey::Hold(id);
+    let hold = e.storage().persistent().get::<DataKey, Hold>(&key);
+    if hold.is_some() {
+        // Kayıt bulunduysa, kalıcı depolamada canlı kalması için TTL'sini uzatırız.
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+    hold
+}
+
+// 'read_hold' fonksiyonu, verilen kimliğe sahip rezervasyon kaydını okur; bulunamazsa program panikler.
+pub fn read_hold(e: &Env, id: u32) -> Hold {
+    try_read_hold(e, id).unwrap_or_else(|| panic!("hold not found"))
+}
+
+// 'write_hold' fonksiyonu, rezervasyon kaydını kalıcı depolamaya yazar ve TTL'sini uzatır.
+pub fn write_hold(e: &Env, id: u32, hold: &Hold) {
+    let key = DataKey::Hold(id);
+    e.storage().persistent().set(&key, hold);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'read_account_holds' fonksiyonu, bir hesabın üzerindeki rezervasyon kimliklerinin listesini okur.
+fn read_account_holds(e: &Env, addr: &Address) -> Vec<u32> {
+    let key = DataKey::AccountHolds(addr.clone());
+    if let Some(ids) = e.storage().persistent().get::<DataKey, Vec<u32>>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        ids
+    } else {
+        Vec::new(e)
+    }
+}
+
+// 'write_account_holds' fonksiyonu, bir hesabın rezervasyon listesini yazar; liste boşsa girdiyi siler.
+fn write_account_holds(e: &Env, addr: &Address, ids: &Vec<u32>) {
+    let key = DataKey::AccountHolds(addr.clone());
+    if ids.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, ids);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+}
+
+// 'add_account_hold' fonksiyonu, yeni bir rezervasyon kimliğini hesabın listesine ekler.
+// Bu sırada süresi dolmuş rezervasyonların kayıtları temizlenir, böylece liste sınırsız büyümez.
+pub fn add_account_hold(e: &Env, addr: &Address, id: u32) {
+    let mut ids = Vec::new(e);
+    for existing in read_account_holds(e, addr).iter() {
+        match try_read_hold(e, existing) {
+            Some(hold) if is_hold_active(e, &hold) => ids.push_back(existing), // Aktif rezervasyonlar korunur.
+            Some(_) => e.storage().persistent().remove(&DataKey::Hold(existing)), // Süresi dolanlar silinir.
+            None => {}
+        }
+    }
+    ids.push_back(id);
+    write_account_holds(e, addr, &ids);
+}
+
+// 'remove_hold' fonksiyonu, tamamen tahsil edilmiş veya serbest bırakılmış bir rezervasyonu
+// hem kayıtlardan hem de hesabın rezervasyon listesinden siler.
+pub fn remove_hold(e: &Env, id: u32, hold: &Hold) {
+    e.storage().persistent().remove(&DataKey::Hold(id));
+    let mut ids = read_account_holds(e, &hold.from);
+    if let Some(index) = ids.first_index_of(id) {
+        ids.remove(index);
+    }
+    write_account_holds(e, &hold.from, &ids);
+}
+
+// 'read_held_total' fonksiyonu, bir hesabın aktif rezervasyonlarında tutulan toplam miktarı döndürür.
+// Süresi dolmuş rezervasyonlar toplama dahil edilmez (otomatik serbest bırakma).
+pub fn read_held_total(e: &Env, addr: &Address) -> i128 {
+    let mut total = 0;
+    for id in read_account_holds(e, addr).iter() {
+        if let Some(hold) = try_read_hold(e, id) {
+            if is_hold_active(e, &hold) {
+                total += hold.amount;
+            }
+        }
+    }
+    total
+}
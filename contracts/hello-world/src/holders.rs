@@ -0,0 +1,89 @@
+// Bu dosya (holders.rs), 'soroban-token-contract' kütüphanesinin 'holders' modülünü oluşturur.
+// 'DataKey::Balance(Address)' girdileri numaralandırılamadığı için, bakiyesi sıfırdan büyük olan hesapların
+// (token sahiplerinin) ayrı bir kaydı tutulur. Kayıt, 'balance' modülündeki 'write_balance' tarafından güncellenir:
+// bir hesabın bakiyesi ilk kez sıfırdan büyük olduğunda eklenir, sıfıra düştüğünde çıkarılır.
+// Her sahip için iki kalıcı girdi tutulur (sıra -> adres ve adres -> sıra); böylece ekleme ve çıkarma
+// sabit maliyetlidir ve depolama maliyeti sahip başına girdiye yayılır. Sahip sayısı 'instance' depolamada tutulur.
+
+use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{Address, Env, Vec};
+
+// 'read_holder_count' fonksiyonu, kayıtlı token sahibi sayısını döndürür.
+pub fn read_holder_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get::<_, u32>(&DataKey::HolderCount)
+        .unwrap_or(0)
+}
+
+// 'write_holder_count' fonksiyonu, kayıtlı token sahibi sayısını günceller.
+fn write_holder_count(e: &Env, count: u32) {
+    e.storage().instance().set(&DataKey::HolderCount, &count);
+}
+
+// 'is_holder' fonksiyonu, adresin sahip kaydında olup olmadığını kontrol eder.
+pub fn is_holder(e: &Env, addr: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::HolderIndex(addr.clone()))
+}
+
+// 'write_holder_at' fonksiyonu, verilen sıraya adresi yazar ve adresin sırasını günceller.
+fn write_holder_at(e: &Env, index: u32, addr: &Address) {
+    let holder_key = DataKey::Holder(index);
+    let index_key = DataKey::HolderIndex(addr.clone());
+    e.storage().persistent().set(&holder_key, addr);
+    e.storage().persistent().set(&index_key, &index);
+    e.storage()
+        .persistent()
+        .extend_ttl(&holder_key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    e.storage()
+        .persistent()
+        .extend_ttl(&index_key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'add_holder' fonksiyonu, adresi (kayıtta değilse) sahip kaydının sonuna ekler.
+pub fn add_holder(e: &Env, addr: &Address) {
+    if is_holder(e, addr) {
+        return;
+    }
+    let count = read_holder_count(e);
+    write_holder_at(e, count, addr);
+    write_holder_count(e, count + 1);
+}
+
+// 'remove_holder' fonksiyonu, adresi (kayıttaysa) sahip kaydından çıkarır.
+// Kaydı boşluksuz tutmak için son sıradaki adres, çıkarılan adresin yerine taşınır.
+pub fn remove_holder(e: &Env, addr: &Address) {
+    let index_key = DataKey::HolderIndex(addr.clone());
+    let Some(index) = e.storage().persistent().get::<DataKey, u32>(&index_key) else {
+        return;
+    };
+    let last = read_holder_count(e) - 1;
+    if index != last {
+        let moved: Address = e
+            .storage()
+            .persistent()
+            .get(&DataKey::Holder(last))
+            .unwrap();
+        write_holder_at(e, index, &moved); // Son adresi boşalan sıraya taşı.
+    }
+    e.storage().persistent().remove(&DataKey::Holder(last));
+    e.storage().persistent().remove(&index_key);
+    write_holder_count(e, last);
+}
+
+// 'read_holders' fonksiyonu, 'start' sırasından başlayarak en fazla 'limit' adet sahip adresini döndürür.
+pub fn read_holders(e: &Env, start: u32, limit: u32) -> Vec<Address> {
+    let end = start.saturating_add(limit).min(read_holder_count(e));
+    let mut holders = Vec::new(e);
+    for index in start..end {
+        let key = DataKey::Holder(index);
+        let holder: Address = e.storage().persistent().get(&key).unwrap();
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        holders.push_back(holder);
+    }
+    holders
+}
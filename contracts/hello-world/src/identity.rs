@@ -0,0 +1,114 @@
+// Bu dosya (identity.rs), 'soroban-token-contract' kütüphanesinin 'identity' modülünü oluşturur.
+// Bu modül, 'Address' ile anahtarlanan bir kimlik kaydı (identity registry) tutar: yetkili beyan yayıncıları
+// (yönetici veya 'Role::ClaimIssuer' rolündekiler) hesaplara ülke, yatırımcı türü, nitelikli yatırımcı gibi
+// türü belirli beyanlar (claim) verir ve geri alır. Her beyan isteğe bağlı bir son geçerlilik defteri taşır.
+// Yönetici, transferlerde alıcının sahip olması gereken beyan konularını belirleyebilir; 'transfer' ve
+// 'transfer_from' bu kurala göre alıcıyı denetler. Beyanlar hesap başına 'persistent' depolamada saklanır.
+
+use crate::admin::{has_role, try_read_administrator};
+use crate::storage_types::{
+    Claim, ClaimTopic, DataKey, Role, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{Address, Env, Map, Vec};
+
+// 'read_claims' fonksiyonu, bir hesabın tüm beyanlarını (süresi dolmuş olanlar dahil) konuya göre okur.
+fn read_claims(e: &Env, account: &Address) -> Map<ClaimTopic, Claim> {
+    let key = DataKey::Claims(account.clone());
+    if let Some(claims) = e
+        .storage()
+        .persistent()
+        .get::<DataKey, Map<ClaimTopic, Claim>>(&key)
+    {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        claims
+    } else {
+        Map::new(e)
+    }
+}
+
+// 'write_claims' fonksiyonu, bir hesabın beyanlarını yazar; hiç beyan kalmadıysa girdiyi siler.
+fn write_claims(e: &Env, account: &Address, claims: &Map<ClaimTopic, Claim>) {
+    let key = DataKey::Claims(account.clone());
+    if claims.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, claims);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+}
+
+// 'is_claim_valid' fonksiyonu, beyanın süresinin dolmadığını ve yayıncısının hâlâ yetkili olduğunu kontrol eder.
+// Yayıncı rolü geri alınan beyanlar geçersiz sayılır.
+fn is_claim_valid(e: &Env, claim: &Claim) -> bool {
+    claim
+        .expiration_ledger
+        .is_none_or(|ledger| ledger >= e.ledger().sequence())
+        && (try_read_administrator(e).as_ref() == Some(&claim.issuer)
+            || has_role(e, Role::ClaimIssuer, &claim.issuer))
+}
+
+// 'write_claim' fonksiyonu, hesaba verilen konuda bir beyan yazar; aynı konudaki önceki beyanın yerini alır.
+pub fn write_claim(e: &Env, account: &Address, claim: Claim) {
+    if let Some(expiration_ledger) = claim.expiration_ledger {
+        if expiration_ledger < e.ledger().sequence() {
+            panic!("expiration_ledger is less than ledger seq");
+        }
+    }
+    let mut claims = read_claims(e, account);
+    claims.set(claim.topic, claim);
+    write_claims(e, account, &claims);
+}
+
+// 'remove_claim' fonksiyonu, hesabın verilen konudaki beyanını siler ve silinen beyanı döndürür.
+// Beyan yoksa program panikler.
+pub fn remove_claim(e: &Env, account: &Address, topic: ClaimTopic) -> Claim {
+    let mut claims = read_claims(e, account);
+    let claim = claims.get(topic).unwrap_or_else(|| panic!("claim not found"));
+    claims.remove(topic);
+    write_claims(e, account, &claims);
+    claim
+}
+
+// 'read_valid_claims' fonksiyonu, bir hesabın geçerli (süresi dolmamış ve yetkili yayıncılı) beyanlarını döndürür.
+pub fn read_valid_claims(e: &Env, account: &Address) -> Vec<Claim> {
+    let mut valid = Vec::new(e);
+    for claim in read_claims(e, account).values().iter() {
+        if is_claim_valid(e, &claim) {
+            valid.push_back(claim);
+        }
+    }
+    valid
+}
+
+// 'read_required_claims' fonksiyonu, transferlerde alıcının sahip olması gereken beyan konularını okur.
+pub fn read_required_claims(e: &Env) -> Vec<ClaimTopic> {
+    e.storage()
+        .instance()
+        .get(&DataKey::RequiredClaims)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+// 'write_required_claims' fonksiyonu, transferlerde alıcının sahip olması gereken beyan konularını ayarlar.
+pub fn write_required_claims(e: &Env, topics: &Vec<ClaimTopic>) {
+    e.storage().instance().set(&DataKey::RequiredClaims, topics);
+}
+
+// 'check_required_claims' fonksiyonu, alıcının gerekli konuların her birinde geçerli bir beyanı olduğunu
+// kontrol eder; eksik bir beyan varsa program panikler.
+pub fn check_required_claims(e: &Env, account: &Address) {
+    let required = read_required_claims(e);
+    if required.is_empty() {
+        return;
+    }
+    let claims = read_claims(e, account);
+    for topic in required.iter() {
+        match claims.get(topic) {
+            Some(claim) if is_claim_valid(e, &claim) => {}
+            _ => panic!("recipient is missing a required claim"),
+        }
+    }
+}
@@ -10,23 +10,67 @@
 
 mod admin;         // 'admin' adlı modülü (ve projedeki admin.rs dosyasını) bu kütüphaneye dahil eder.
                    // Bu modül, kontratın yönetimsel işlevlerini içerir.
+mod airdrop;       // 'airdrop' adlı modülü (ve airdrop.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, Merkle kanıtıyla talep edilen airdrop'ları ve talep bit haritalarını yönetir.
 mod allowance;     // 'allowance' adlı modülü (ve allowance.rs dosyasını) bu kütüphaneye dahil eder.
                    // Bu modül, token harcama izinleri (allowance) ile ilgili mantığı içerir.
+mod allowlist;     // 'allowlist' adlı modülü (ve allowlist.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, izin listesi (KYC) modunda token tutabilecek hesapların listesini yönetir.
 mod balance;       // 'balance' adlı modülü (ve balance.rs dosyasını) bu kütüphaneye dahil eder.
                    // Bu modül, kullanıcıların token bakiyelerini yönetme işlevlerini içerir.
+mod compliance;    // 'compliance' adlı modülü (ve compliance.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, transfer kurallarını harici bir uyumluluk kontratına devreder.
 mod contract;      // 'contract' adlı modülü (ve contract.rs dosyasını) bu kütüphaneye dahil eder.
                    // Bu modül, genellikle ana kontrat mantığını ve Soroban trait implementasyonlarını barındırır.
+mod council;       // 'council' adlı modülü (ve council.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, yöneticinin yerini alabilen M-of-N imzacılı yönetici konseyini ve önerilerini yönetir.
+mod flash;         // 'flash' adlı modülü (ve flash.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, tek bir çağrı içinde basılıp geri alınan anlık kredileri (flash loan) yönetir.
+mod freeze;        // 'freeze' adlı modülü (ve freeze.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, hesap dondurma kayıtlarını (neden kodu, yetkili ve süre ile) yönetir.
+mod hold;          // 'hold' adlı modülü (ve hold.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, bakiye rezervasyonlarını (hold) ve tahsil edilmelerini yönetir.
+mod holders;       // 'holders' adlı modülü (ve holders.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, bakiyesi olan hesapların sayfalanabilir kaydını (holder registry) tutar.
+mod identity;      // 'identity' adlı modülü (ve identity.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, hesaplara ait kimlik beyanlarının (claim) kaydını yönetir.
+mod limits;        // 'limits' adlı modülü (ve limits.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, en fazla sahip sayısı ve hesap başına en fazla bakiye sınırlarını yönetir.
 mod metadata;      // 'metadata' adlı modülü (ve metadata.rs dosyasını) bu kütüphaneye dahil eder.
                    // Bu modül, token'ın adı, sembolü, ondalık sayısı gibi meta verilerini yönetir.
+mod recovery;      // 'recovery' adlı modülü (ve recovery.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, hesap sahiplerinin belirlediği koruyucularla sosyal kurtarmayı yönetir.
+mod rewards;       // 'rewards' adlı modülü (ve rewards.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, token sahiplerine paylarıyla orantılı ödül dağıtımını ve ödül taleplerini yönetir.
+mod shares;        // 'shares' adlı modülü (ve shares.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, faiz getiren (rebasing) bakiyeler için pay ve endeks muhasebesini yönetir.
 mod storage_types; // 'storage_types' adlı modülü (ve storage_types.rs dosyasını) bu kütüphaneye dahil eder.
                    // Bu modül, kontratın depolama için kullandığı özel veri türlerini tanımlar.
+mod stream;        // 'stream' adlı modülü (ve stream.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, hesaplar arasındaki sürekli ödeme akışlarını (stream) yönetir.
+mod timelock;      // 'timelock' adlı modülü (ve timelock.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, yönetici işlemlerinin zaman kilidi kuyruğunu (planlama, yürütme, iptal) yönetir.
+mod upgrade;       // 'upgrade' adlı modülü (ve upgrade.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, kontratın depolama sürümünü ve sürümler arası depolama geçişlerini yönetir.
+mod wrapper;       // 'wrapper' adlı modülü (ve wrapper.rs dosyasını) bu kütüphaneye dahil eder.
+                   // Bu modül, mevcut bir SEP-41 varlığını 1:1 saran sarmalayıcı modunu yönetir.
 mod test;          // 'test' adlı modülü (ve test.rs dosyasını) bu kütüphaneye dahil eder.
                    // Bu modül, kontratın işlevselliğini doğrulamak için birim testlerini ve entegrasyon testlerini içerir.
 
-pub use crate::contract::TokenClient; // 'contract' modülü içinde tanımlanan 'TokenClient' adlı öğeyi
-                                      // (muhtemelen bir struct veya enum) bu kütüphanenin dışından
-                                      // doğrudan erişilebilir (public) hale getirir.
-                                      // Bu, kütüphaneyi kullanan diğer kodların veya testlerin
-                                      // 'TokenClient'a `kutuphane_adi::TokenClient` yerine
-                                      // `kutuphane_adi::contract::TokenClient` yazmadan erişmesini sağlar.
-                                      // Genellikle, kontratla etkileşim kurmak için bir istemci (client) arayüzü sağlar.
\ No newline at end of file
+pub use crate::contract::{Token, TokenClient}; // 'contract' modülü içinde tanımlanan 'Token' kontratını ve 'TokenClient' istemcisini
+                                               // bu kütüphanenin dışından doğrudan erişilebilir (public) hale getirir.
+                                               // Bu, kütüphaneyi kullanan diğer kodların veya testlerin
+                                               // 'TokenClient'a `kutuphane_adi::TokenClient` yerine
+                                               // `kutuphane_adi::contract::TokenClient` yazmadan erişmesini sağlar.
+                                               // 'Token'ın dışa açılması, kontratın başka kontratların testlerinde
+                                               // kurucu argümanlarıyla 'e.register(Token, (admin, ...))' şeklinde
+                                               // kaydedilebilmesini de sağlar.
+pub use crate::limits::LimitError; // Sahip ve bakiye sınırlarının tipli hata kodlarını dış kodlar için erişilebilir hale getirir.
+pub use crate::metadata::MetadataError; // Ad ve sembol doğrulamasının tipli hata kodlarını, 'try_update_metadata' sonuçlarını
+                                        // ayırt etmek isteyen dış kodlar için erişilebilir hale getirir.
+pub use crate::storage_types::{
+    AccessMode, AdminCouncil, AdminOp, Airdrop, Claim, ClaimTopic, FreezeInfo, GuardianConfig,
+    MerkleProof, Proposal, Recovery, RewardAccount, Role, ScheduledOp,
+}; // Kontrat arayüzünde kullanılan erişim modu, rol, beyan, yönetici işlemi, konsey,
+   // dondurma, kurtarma, ödül ve airdrop türlerini, 'TokenClient' ile çalışan dış kodlar için
+   // erişilebilir hale getirir.
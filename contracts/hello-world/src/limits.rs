@@ -0,0 +1,87 @@
+// Bu dosya (limits.rs), 'soroban-token-contract' kütüphanesinin 'limits' modülünü oluşturur.
+// Bu modül, menkul kıymet niteliğindeki tokenlar için yasal sınırları yönetir:
+// en fazla token sahibi sayısı ('max_holders') ve tek bir hesabın tutabileceği en fazla bakiye ('max_balance_per_account').
+// Sınırlar 'balance' modülündeki 'receive_balance' içinde uygulanır; böylece basım (mint), transfer ve
+// bakiye alan diğer tüm yollar sınırlara uyar. Hazine (treasury) adresi her iki sınırdan da muaftır.
+
+use crate::admin::read_treasury;
+use crate::holders::{is_holder, read_holder_count};
+use crate::storage_types::DataKey;
+use soroban_sdk::{contracterror, panic_with_error, Address, Env};
+
+// 'LimitError', sahip ve bakiye sınırları aşıldığında dönen tipli hata kodlarıdır.
+// İstemciler bu kodları 'try_mint', 'try_transfer' gibi çağrıların sonuçlarından ayırt edebilir.
+// Kodlar, 'MetadataError' kodlarıyla çakışmayacak şekilde 10'dan başlar.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LimitError {
+    MaxBalancePerAccountExceeded = 10, // Alıcının bakiyesi hesap başına en fazla bakiyeyi aşacak.
+    MaxHoldersExceeded = 11,           // Yeni bir sahip, en fazla sahip sayısını aşacak.
+}
+
+// 'read_max_holders' fonksiyonu, yapılandırılmış en fazla sahip sayısını (varsa) okur.
+pub fn read_max_holders(e: &Env) -> Option<u32> {
+    e.storage().instance().get(&DataKey::MaxHolders)
+}
+
+// 'write_max_holders' fonksiyonu, en fazla sahip sayısını ayarlar; 'None' sınırı kaldırır.
+pub fn write_max_holders(e: &Env, max_holders: Option<u32>) {
+    match max_holders {
+        Some(max) => e.storage().instance().set(&DataKey::MaxHolders, &max),
+        None => e.storage().instance().remove(&DataKey::MaxHolders),
+    }
+}
+
+// 'read_max_balance_per_account' fonksiyonu, hesap başına en fazla bakiyeyi (varsa) okur.
+pub fn read_max_balance_per_account(e: &Env) -> Option<i128> {
+    e.storage().instance().get(&DataKey::MaxBalancePerAccount)
+}
+
+// 'write_max_balance_per_account' fonksiyonu, hesap başına en fazla bakiyeyi ayarlar; 'None' sınırı kaldırır.
+pub fn write_max_balance_per_account(e: &Env, max_balance: Option<i128>) {
+    match max_balance {
+        Some(max) => e
+            .storage()
+            .instance()
+            .set(&DataKey::MaxBalancePerAccount, &max),
+        None => e.storage().instance().remove(&DataKey::MaxBalancePerAccount),
+    }
+}
+
+// 'check_limits' fonksiyonu, 'addr' adresinin bakiyesi 'balance' değerinden 'new_balance' değerine
+// çıkarken sınırların aşılıp aşılmadığını kontrol eder; aşılıyorsa tipli bir 'LimitError' ile panikler.
+pub fn check_limits(e: &Env, addr: &Address, balance: i128, new_balance: i128) {
+    let treasury = read_treasury(e);
+    if treasury.as_ref() == Some(addr) {
+        return; // Hazine sınırlardan muaftır.
+    }
+    let contract = e.current_contract_address();
+    if *addr == contract {
+        return; // Kontratın kendi bakiyesi emanettir; sınırlardan muaftır.
+    }
+
+    if let Some(max_balance) = read_max_balance_per_account(e) {
+        if new_balance > max_balance {
+            panic_with_error!(e, LimitError::MaxBalancePerAccountExceeded);
+        }
+    }
+
+    // Sahip sınırı yalnızca bakiyesi sıfırken token alan (yeni sahip olacak) hesaplar için kontrol edilir.
+    if balance == 0 && new_balance > 0 {
+        if let Some(max_holders) = read_max_holders(e) {
+            let mut holders = read_holder_count(e);
+            if let Some(treasury) = treasury {
+                if is_holder(e, &treasury) {
+                    holders -= 1; // Hazine, sahip sayısına dahil edilmez.
+                }
+            }
+            if is_holder(e, &contract) {
+                holders -= 1; // Emaneti tutan kontrat da sahip sayısına dahil edilmez.
+            }
+            if holders >= max_holders {
+                panic_with_error!(e, LimitError::MaxHoldersExceeded);
+            }
+        }
+    }
+}
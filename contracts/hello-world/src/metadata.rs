@@ -5,14 +5,68 @@
 // İşlemler için 'soroban_token_sdk' kütüphanesindeki 'TokenUtils' ve 'TokenMetadata' yapılarını kullanır.
 // 'TokenUtils' SDK'sı, meta verilerin standart bir şekilde depolanmasını ve erişilmesini kolaylaştırır.
 
-use soroban_sdk::{Env, String}; // soroban_sdk kütüphanesinden 'Env' (çalışma ortamı) ve 'String' (Soroban string türü)
-                                // türlerini içeri aktarır.
+use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD}; // Genişletilmiş meta veri alanlarının depolama anahtarları ve TTL sabitleri.
+use soroban_sdk::{contracterror, panic_with_error, Env, String, Symbol}; // soroban_sdk kütüphanesinden 'Env' (çalışma ortamı), 'String'
+                                                                         // (Soroban string türü), 'Symbol' (genişletilmiş meta veri alanlarının anahtarı) türlerini
+                                                                         // ve tipli hata kodları için 'contracterror' ile 'panic_with_error' makrolarını içeri aktarır.
 use soroban_token_sdk::{metadata::TokenMetadata, TokenUtils}; // soroban_token_sdk kütüphanesinden:
                                                               // - 'metadata::TokenMetadata': Token meta verilerini (decimal, name, symbol)
                                                               //   bir arada tutan yapıyı içeri aktarır.
                                                               // - 'TokenUtils': Token ile ilgili yardımcı fonksiyonlara, özellikle
                                                               //   meta veri yönetimine erişim sağlayan bir araç yapısını içeri aktarır.
 
+pub(crate) const MAX_NAME_LENGTH: u32 = 64;   // Token adının bayt cinsinden en fazla uzunluğu.
+pub(crate) const MAX_SYMBOL_LENGTH: u32 = 12; // Token sembolünün en fazla uzunluğu (cüzdanların gösterebileceği kısa kod).
+pub(crate) const MAX_FIELD_LENGTH: u32 = 256; // Genişletilmiş meta veri alanı değerinin bayt cinsinden en fazla uzunluğu.
+
+// 'METADATA_FIELDS', ayarlanabilecek genişletilmiş meta veri alanlarının anahtarlarıdır.
+const METADATA_FIELDS: [&str; 5] = ["icon", "home_domain", "description", "website", "issuer"];
+
+// 'MetadataError', ad ve sembol doğrulamasında dönen tipli hata kodlarıdır.
+// İstemciler bu kodları kurucu ve 'try_update_metadata' sonuçlarından ayırt edebilir.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MetadataError {
+    EmptyName = 1,                // Token adı boş.
+    NameTooLong = 2,              // Token adı 'MAX_NAME_LENGTH' bayttan uzun.
+    InvalidNameCharacter = 3,     // Token adı kontrol karakteri (satır sonu, sekme vb.) içeriyor.
+    InvalidSymbolLength = 4,      // Token sembolü boş veya 'MAX_SYMBOL_LENGTH' karakterden uzun.
+    InvalidSymbolCharacter = 5,   // Token sembolü ASCII harf ve rakam dışında bir karakter içeriyor.
+    InvalidFieldKey = 6,          // Meta veri alanı anahtarı 'METADATA_FIELDS' içinde değil.
+    FieldValueTooLong = 7,        // Meta veri alanı değeri 'MAX_FIELD_LENGTH' bayttan uzun.
+}
+
+// 'check_name_symbol' fonksiyonu, token adını ve sembolünü doğrular; geçersizse tipli bir hatayla panikler.
+// Ad boş olmamalı, en fazla 'MAX_NAME_LENGTH' bayt olmalı ve kontrol karakteri içermemelidir.
+// Sembol 1 ile 'MAX_SYMBOL_LENGTH' arasında uzunlukta olmalı ve yalnızca ASCII harf ve rakamlardan oluşmalıdır.
+pub fn check_name_symbol(e: &Env, name: &String, symbol: &String) {
+    let name_len = name.len();
+    if name_len == 0 {
+        panic_with_error!(e, MetadataError::EmptyName);
+    }
+    if name_len > MAX_NAME_LENGTH {
+        panic_with_error!(e, MetadataError::NameTooLong);
+    }
+    let mut buf = [0u8; MAX_NAME_LENGTH as usize];
+    let name_bytes = &mut buf[..name_len as usize];
+    name.copy_into_slice(name_bytes);
+    if name_bytes.iter().any(|b| b.is_ascii_control()) {
+        panic_with_error!(e, MetadataError::InvalidNameCharacter);
+    }
+
+    let symbol_len = symbol.len();
+    if symbol_len == 0 || symbol_len > MAX_SYMBOL_LENGTH {
+        panic_with_error!(e, MetadataError::InvalidSymbolLength);
+    }
+    let mut buf = [0u8; MAX_SYMBOL_LENGTH as usize];
+    let symbol_bytes = &mut buf[..symbol_len as usize];
+    symbol.copy_into_slice(symbol_bytes);
+    if !symbol_bytes.iter().all(|b| b.is_ascii_alphanumeric()) {
+        panic_with_error!(e, MetadataError::InvalidSymbolCharacter);
+    }
+}
+
 // 'read_decimal' fonksiyonu, token'ın depolanmış ondalık basamak sayısını okur.
 pub fn read_decimal(e: &Env) -> u32 { // 'e': Soroban çalışma zamanı ortamına bir referans.
                                      // Fonksiyon, bir 'u32' (ondalık sayısı) değeri döndürür.
@@ -42,10 +96,53 @@ pub fn read_symbol(e: &Env) -> String { // 'e': Soroban çalışma zamanı ortam
 }
 
 // 'write_metadata' fonksiyonu, verilen 'TokenMetadata' yapısını depolamaya yazar.
-// Bu, genellikle kontratın 'initialize' fonksiyonunda token'ın ilk meta verilerini ayarlamak için kullanılır.
+// Bu, genellikle kontratın kurucusunda ('__constructor') token'ın ilk meta verilerini ayarlamak için kullanılır.
 pub fn write_metadata(e: &Env, metadata: TokenMetadata) { // 'e': Soroban çalışma zamanı ortamı.
                                                           // 'metadata': Yazılacak 'TokenMetadata' yapısı.
     let util = TokenUtils::new(e);                        // Yeni bir 'TokenUtils' örneği oluşturur.
     util.metadata()                                       // Meta veri yönetimi kısmına erişir.
         .set_metadata(&metadata);                         // Verilen 'metadata' referansını kullanarak depolamadaki meta verileri ayarlar/günceller.
-}
\ No newline at end of file
+}
+
+// 'write_name_symbol' fonksiyonu, token'ın adını ve sembolünü günceller (örneğin yeniden markalama için).
+// Ondalık sayısı değiştirilemez; mevcut değer korunur. Yeni ad ve sembol kurucu ile aynı kurallarla doğrulanır.
+pub fn write_name_symbol(e: &Env, name: String, symbol: String) {
+    check_name_symbol(e, &name, &symbol);                 // Geçersiz ad veya sembolü tipli bir hatayla reddet.
+    let decimal = read_decimal(e);                        // Mevcut ondalık sayısını koru.
+    write_metadata(e, TokenMetadata { decimal, name, symbol });
+}
+
+// 'read_metadata_field' fonksiyonu, genişletilmiş meta veri alanının (örneğin 'icon', 'home_domain') değerini okur.
+// Alan ayarlanmamışsa 'None' döner.
+pub fn read_metadata_field(e: &Env, key: Symbol) -> Option<String> {
+    let key = DataKey::MetadataField(key);
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+    value
+}
+
+// 'write_metadata_field' fonksiyonu, genişletilmiş meta veri alanını yazar. Boş bir değer alanı siler.
+// Anahtar 'METADATA_FIELDS' içinde olmalı ve değer en fazla 'MAX_FIELD_LENGTH' bayt olmalıdır; aksi hâlde
+// tipli bir hatayla panikler. Alanlar, her çağrıda yüklenen 'instance' depolamayı büyütmemek için
+// 'persistent' depolamada tutulur.
+pub fn write_metadata_field(e: &Env, key: Symbol, value: &String) {
+    if !METADATA_FIELDS.iter().any(|field| key == Symbol::new(e, field)) {
+        panic_with_error!(e, MetadataError::InvalidFieldKey);
+    }
+    if value.len() > MAX_FIELD_LENGTH {
+        panic_with_error!(e, MetadataError::FieldValueTooLong);
+    }
+    let key = DataKey::MetadataField(key);
+    if value.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, value);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+}
@@ -0,0 +1,87 @@
+// Bu dosya (recovery.rs), 'soroban-token-contract' kütüphanesinin 'recovery' modülünü oluşturur.
+// Bu modül, yönetici zorunlu transferine alternatif olarak sosyal kurtarmayı (social recovery) yönetir:
+// hesap sahibi kendi koruyucularını (guardian), onay eşiğini ve bekleme süresini belirler. Bir koruyucu
+// yeni bir adres önerir, diğer koruyucular onaylar; eşiğe ulaşıldıktan sonra bekleme süresi dolunca
+// kurtarma yürütülebilir. Sahip, anahtarı hâlâ elindeyse kurtarmayı yürütülmeden önce iptal edebilir.
+// Koruyucu ayarları ve bekleyen kurtarmalar hesap başına 'persistent' depolamada saklanır.
+
+use crate::storage_types::{
+    DataKey, GuardianConfig, Recovery, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{Address, Env};
+
+// 'read_guardians' fonksiyonu, bir hesabın koruyucu ayarlarını (varsa) okur.
+pub fn read_guardians(e: &Env, owner: &Address) -> Option<GuardianConfig> {
+    let key = DataKey::Guardians(owner.clone());
+    let config = e.storage().persistent().get(&key);
+    if config.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+    config
+}
+
+// 'write_guardians' fonksiyonu, bir hesabın koruyucu ayarlarını yazar; koruyucu listesi boşsa ayarları siler.
+pub fn write_guardians(e: &Env, owner: &Address, config: &GuardianConfig) {
+    let key = DataKey::Guardians(owner.clone());
+    if config.guardians.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, config);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+}
+
+// 'require_guardian' fonksiyonu, 'guardian' adresinin 'owner' hesabının koruyucusu olduğunu doğrular ve
+// işlemi imzalamasını zorunlu kılar; koruyucu değilse program panikler. Hesabın koruyucu ayarlarını döndürür.
+pub fn require_guardian(e: &Env, owner: &Address, guardian: &Address) -> GuardianConfig {
+    let config = read_guardians(e, owner).unwrap_or_else(|| panic!("account has no guardians"));
+    if !config.guardians.contains(guardian) {
+        panic!("caller is not a guardian of the account");
+    }
+    guardian.require_auth();
+    config
+}
+
+// 'read_recovery' fonksiyonu, bir hesap için bekleyen kurtarma önerisini (varsa) okur.
+pub fn read_recovery(e: &Env, owner: &Address) -> Option<Recovery> {
+    let key = DataKey::Recovery(owner.clone());
+    let recovery = e.storage().persistent().get(&key);
+    if recovery.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+    recovery
+}
+
+// 'write_recovery' fonksiyonu, bir hesap için bekleyen kurtarma önerisini yazar.
+pub fn write_recovery(e: &Env, owner: &Address, recovery: &Recovery) {
+    let key = DataKey::Recovery(owner.clone());
+    e.storage().persistent().set(&key, recovery);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'remove_recovery' fonksiyonu, bir hesap için bekleyen kurtarma önerisini siler.
+pub fn remove_recovery(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Recovery(owner.clone()));
+}
+
+// 'add_approval' fonksiyonu, kurtarma önerisine koruyucunun onayını ekler. Onay eşiğine ilk kez
+// ulaşıldığında, sahibin iptal edebilmesi için bekleme süresinin bittiği defteri belirler.
+pub fn add_approval(e: &Env, config: &GuardianConfig, recovery: &mut Recovery, guardian: Address) {
+    if recovery.approvals.contains(&guardian) {
+        panic!("guardian has already approved");
+    }
+    recovery.approvals.push_back(guardian);
+    if recovery.executable_at_ledger.is_none() && recovery.approvals.len() >= config.threshold {
+        recovery.executable_at_ledger = Some(e.ledger().sequence() + config.delay_ledgers);
+    }
+}
@@ -0,0 +1,201 @@
+// Bu dosya (rewards.rs), 'soroban-token-contract' kütüphanesinin 'rewards' modülünü oluşturur.
+// Bu modül, token sahiplerine (bu token'da veya başka bir SEP-41 token'ında) paylarıyla orantılı ödül dağıtımını
+// yönetir. Her ödül token'ı için pay başına kümülatif ödül ('reward per share') tutulur; 'distribute' yalnızca bu
+// birikimi artırır. Her hesabın en son gördüğü birikim ve tahakkuk etmiş ödülü, hesabın payı değişmeden hemen önce
+// ('balance' modülündeki 'write_shares' içinde) güncellenir. Böylece dağıtım ve talep (claim) maliyeti token sahibi
+// sayısından bağımsızdır; yalnızca kayıtlı ödül token'ı sayısıyla ('MAX_REWARD_TOKENS') sınırlı olarak büyür.
+// Kontratın kendi adresi, bu token'la yapılan dağıtımların emanetini tuttuğu için ödül kazanmaz.
+// Ödül token'ları yalnızca yönetici tarafından kaydedilir ve kayıttan çıkarılabilir. Kayıttan çıkarılan token'a
+// dağıtım yapılamaz; son pay başına birikimi saklanır ve hesaplar bu değere kadar tahakkuk ettirilmeye devam eder,
+// böylece daha önce dağıtılmış ödüllerin tamamı talep edilebilir. Çıkarılan token yeniden kaydedilemez.
+
+use crate::storage_types::{
+    DataKey, RewardAccount, RewardDataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{Address, Env, Vec};
+
+// 'REWARD_SCALE', pay başına ödül birikiminin sabit noktalı ölçeğidir.
+pub(crate) const REWARD_SCALE: i128 = 1_000_000_000_000;
+
+// 'MAX_REWARD_TOKENS', kayıtlı olabilecek en fazla ödül token'ı sayısıdır. Her bakiye değişikliği
+// kayıtlı her ödül token'ı için hesabın kaydını güncellediğinden bu sayı sınırlıdır.
+pub(crate) const MAX_REWARD_TOKENS: u32 = 5;
+
+// 'MAX_RETIRED_REWARD_TOKENS', kayıttan çıkarılabilecek en fazla ödül token'ı sayısıdır. Çıkarılan token'lar için de
+// hesaplar tahakkuk ettirildiğinden bu sayı da sınırlıdır.
+pub(crate) const MAX_RETIRED_REWARD_TOKENS: u32 = 5;
+
+// 'read_reward_tokens' fonksiyonu, kayıtlı ödül token'larının listesini okur.
+pub fn read_reward_tokens(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&DataKey::RewardTokens)
+        .unwrap_or(Vec::new(e))
+}
+
+// 'read_retired_reward_tokens' fonksiyonu, kayıttan çıkarılmış ödül token'larının listesini okur.
+fn read_retired_reward_tokens(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&RewardDataKey::RetiredTokens)
+        .unwrap_or(Vec::new(e))
+}
+
+// 'read_reward_per_share' fonksiyonu, bir ödül token'ı için pay başına kümülatif ödülü okur. Kayıttan çıkarılmış
+// token'lar için çıkarıldığı andaki değer döner.
+pub fn read_reward_per_share(e: &Env, reward_token: &Address) -> i128 {
+    if let Some(frozen) = e
+        .storage()
+        .instance()
+        .get(&RewardDataKey::Retired(reward_token.clone()))
+    {
+        return frozen;
+    }
+    e.storage()
+        .instance()
+        .get(&DataKey::RewardPerShare(reward_token.clone()))
+        .unwrap_or(0)
+}
+
+// 'add_reward_token' fonksiyonu, bir ödül token'ını kaydeder. Token zaten kayıtlıysa, daha önce kayıttan
+// çıkarılmışsa veya 'MAX_REWARD_TOKENS' sınırına ulaşıldıysa program panikler.
+pub fn add_reward_token(e: &Env, reward_token: &Address) {
+    if e.storage().instance().has(&RewardDataKey::Retired(reward_token.clone())) {
+        panic!("reward token was deregistered");
+    }
+    let mut tokens = read_reward_tokens(e);
+    if tokens.contains(reward_token) {
+        panic!("reward token is already registered");
+    }
+    if tokens.len() >= MAX_REWARD_TOKENS {
+        panic!("too many reward tokens");
+    }
+    tokens.push_back(reward_token.clone());
+    e.storage().instance().set(&DataKey::RewardTokens, &tokens);
+}
+
+// 'remove_reward_token' fonksiyonu, bir ödül token'ını kayıttan çıkarır, son pay başına birikimini saklar ve
+// yeniden kaydedilmesini engeller. Token kayıtlı değilse veya 'MAX_RETIRED_REWARD_TOKENS' sınırına ulaşıldıysa
+// program panikler.
+pub fn remove_reward_token(e: &Env, reward_token: &Address) {
+    let mut tokens = read_reward_tokens(e);
+    let Some(index) = tokens.first_index_of(reward_token) else {
+        panic!("reward token is not registered");
+    };
+    let mut retired = read_retired_reward_tokens(e);
+    if retired.len() >= MAX_RETIRED_REWARD_TOKENS {
+        panic!("too many deregistered reward tokens");
+    }
+    let per_share = read_reward_per_share(e, reward_token);
+    tokens.remove(index);
+    retired.push_back(reward_token.clone());
+    e.storage().instance().set(&DataKey::RewardTokens, &tokens);
+    e.storage().instance().set(&RewardDataKey::RetiredTokens, &retired);
+    e.storage()
+        .instance()
+        .set(&RewardDataKey::Retired(reward_token.clone()), &per_share);
+}
+
+// 'read_reward_account' fonksiyonu, bir hesabın bir ödül token'ı için kaydını okur; kayıt yoksa sıfırlarla döner.
+fn read_reward_account(e: &Env, account: &Address, reward_token: &Address) -> RewardAccount {
+    let key = DataKey::RewardAccount(account.clone(), reward_token.clone());
+    match e.storage().persistent().get(&key) {
+        Some(record) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+            record
+        }
+        None => RewardAccount {
+            paid_per_share: 0,
+            accrued: 0,
+        },
+    }
+}
+
+// 'write_reward_account' fonksiyonu, bir hesabın bir ödül token'ı için kaydını yazar.
+fn write_reward_account(e: &Env, account: &Address, reward_token: &Address, record: &RewardAccount) {
+    let key = DataKey::RewardAccount(account.clone(), reward_token.clone());
+    e.storage().persistent().set(&key, record);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'accrue' fonksiyonu, hesabın kaydına son güncellemeden bu yana 'shares' payla kazandığı ödülü ekler.
+fn accrue(record: RewardAccount, shares: i128, per_share: i128) -> RewardAccount {
+    let earned = shares
+        .checked_mul(per_share - record.paid_per_share)
+        .expect("reward overflow")
+        / REWARD_SCALE;
+    RewardAccount {
+        paid_per_share: per_share,
+        accrued: record.accrued + earned,
+    }
+}
+
+// 'checkpoint_rewards' fonksiyonu, 'account' hesabının payı değişmeden hemen önce, mevcut payıyla ('shares')
+// kazandığı ödülleri kayıtlı ve kayıttan çıkarılmış tüm ödül token'ları için tahakkuk ettirir.
+pub fn checkpoint_rewards(e: &Env, account: &Address, shares: i128) {
+    if *account == e.current_contract_address() {
+        return; // Kontratın kendi adresi ödül kazanmaz.
+    }
+    let mut tokens = read_reward_tokens(e);
+    tokens.append(&read_retired_reward_tokens(e));
+    for reward_token in tokens.iter() {
+        let per_share = read_reward_per_share(e, &reward_token);
+        let record = read_reward_account(e, account, &reward_token);
+        if record.paid_per_share != per_share {
+            write_reward_account(e, account, &reward_token, &accrue(record, shares, per_share));
+        }
+    }
+}
+
+// 'pending_rewards' fonksiyonu, 'account' hesabının 'shares' payıyla talep edebileceği ödülü hesaplar.
+pub fn pending_rewards(e: &Env, account: &Address, reward_token: &Address, shares: i128) -> i128 {
+    if *account == e.current_contract_address() {
+        return 0;
+    }
+    let per_share = read_reward_per_share(e, reward_token);
+    accrue(read_reward_account(e, account, reward_token), shares, per_share).accrued
+}
+
+// 'take_rewards' fonksiyonu, 'account' hesabının tahakkuk etmiş ödülünden en fazla 'available' kadarını düşer ve
+// ödenecek miktarı döndürür. Ödenemeyen kalan, daha sonra talep edilmek üzere kayıtta kalır.
+pub fn take_rewards(
+    e: &Env,
+    account: &Address,
+    reward_token: &Address,
+    shares: i128,
+    available: i128,
+) -> i128 {
+    let per_share = read_reward_per_share(e, reward_token);
+    let record = accrue(read_reward_account(e, account, reward_token), shares, per_share);
+    let paid = record.accrued.min(available.max(0));
+    write_reward_account(
+        e,
+        account,
+        reward_token,
+        &RewardAccount {
+            paid_per_share: per_share,
+            accrued: record.accrued - paid,
+        },
+    );
+    paid
+}
+
+// 'add_distribution' fonksiyonu, 'amount' kadar ödülü 'eligible_shares' paya orantılı olarak dağıtır:
+// yalnızca pay başına kümülatif ödülü artırır. Ödül token'ı kayıtlı olmalıdır.
+pub fn add_distribution(e: &Env, reward_token: &Address, amount: i128, eligible_shares: i128) {
+    if !read_reward_tokens(e).contains(reward_token) {
+        panic!("reward token is not registered");
+    }
+    if eligible_shares <= 0 {
+        panic!("no holders to distribute to");
+    }
+    let increase = amount.checked_mul(REWARD_SCALE).expect("reward overflow") / eligible_shares;
+    e.storage().instance().set(
+        &DataKey::RewardPerShare(reward_token.clone()),
+        &(read_reward_per_share(e, reward_token) + increase),
+    );
+}
@@ -0,0 +1,59 @@
+// Bu dosya (shares.rs), 'soroban-token-contract' kütüphanesinin 'shares' modülünü oluşturur.
+// Bu modül, faiz getiren (rebasing) token'lar için pay (share) tabanlı muhasebeyi yönetir.
+// 'DataKey::Balance' hesapların paylarını tutar; bakiye, pay × güncel endeks ('index') olarak hesaplanır.
+// Yönetici veya endeks oracle'ı endeksi güncellediğinde tüm bakiyeler aynı oranda büyür (veya küçülür).
+// Endeks 'INDEX_SCALE' ölçeğinde saklanır ve varsayılanı 1.0'dır; bu durumda pay ve miktar birebir aynıdır,
+// bu yüzden endeksi hiç değiştirilmeyen token'lar (ve sürüm 3'ten önceki bakiyeler) olduğu gibi çalışır.
+// Yuvarlama her zaman token lehinedir: alınan miktarlar paya aşağı, harcanan miktarlar paya yukarı yuvarlanır.
+
+use crate::storage_types::DataKey;
+use soroban_sdk::Env;
+
+// 'INDEX_SCALE', endeksin sabit noktalı ölçeğidir (1.0 = 1_000_000_000).
+pub(crate) const INDEX_SCALE: i128 = 1_000_000_000;
+
+// 'read_index' fonksiyonu, güncel endeksi okur; ayarlanmamışsa 1.0 ('INDEX_SCALE') döndürür.
+pub fn read_index(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::Index).unwrap_or(INDEX_SCALE)
+}
+
+// 'write_index' fonksiyonu, endeksi günceller. Endeks pozitif olmalıdır.
+pub fn write_index(e: &Env, index: i128) {
+    if index <= 0 {
+        panic!("index must be positive");
+    }
+    e.storage().instance().set(&DataKey::Index, &index);
+}
+
+// 'read_total_shares' fonksiyonu, tüm hesapların toplam payını okur.
+pub fn read_total_shares(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::TotalShares).unwrap_or(0)
+}
+
+// 'write_total_shares' fonksiyonu, tüm hesapların toplam payını yazar.
+pub fn write_total_shares(e: &Env, total: i128) {
+    e.storage().instance().set(&DataKey::TotalShares, &total);
+}
+
+// 'shares_to_amount' fonksiyonu, pay miktarını güncel endeksle token miktarına çevirir (aşağı yuvarlanır).
+pub fn shares_to_amount(e: &Env, shares: i128) -> i128 {
+    shares
+        .checked_mul(read_index(e))
+        .expect("share amount overflow")
+        / INDEX_SCALE
+}
+
+// 'amount_to_shares_down' fonksiyonu, alınan (basılan, gelen) token miktarını paya çevirir (aşağı yuvarlanır).
+pub fn amount_to_shares_down(e: &Env, amount: i128) -> i128 {
+    amount
+        .checked_mul(INDEX_SCALE)
+        .expect("share amount overflow")
+        / read_index(e)
+}
+
+// 'amount_to_shares_up' fonksiyonu, harcanan (yakılan, giden) token miktarını paya çevirir (yukarı yuvarlanır).
+pub fn amount_to_shares_up(e: &Env, amount: i128) -> i128 {
+    let index = read_index(e);
+    let scaled = amount.checked_mul(INDEX_SCALE).expect("share amount overflow");
+    scaled / index + if scaled % index > 0 { 1 } else { 0 }
+}
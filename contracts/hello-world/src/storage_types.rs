@@ -6,10 +6,12 @@
 // TTL sabitleri, depolama girişlerinin ne kadar süreyle "canlı" kalacağını ve ne zaman
 // "bump" (uzatma/artırma) işlemi yapılması gerektiğini belirler.
 
-use soroban_sdk::{contracttype, Address}; // soroban_sdk kütüphanesinden:
-                                          // - 'contracttype': Bir Rust türünü (struct veya enum) Soroban kontratlarında
-                                          //   depolanabilir ve kullanılabilir hale getiren bir makro (attribute).
-                                          // - 'Address': Soroban'daki bir hesabı veya kontratı temsil eden adres türü.
+use soroban_sdk::{contracttype, Address, BytesN, Symbol, Vec}; // soroban_sdk kütüphanesinden:
+                                               // - 'contracttype': Bir Rust türünü (struct veya enum) Soroban kontratlarında
+                                               //   depolanabilir ve kullanılabilir hale getiren bir makro (attribute).
+                                               // - 'Address': Soroban'daki bir hesabı veya kontratı temsil eden adres türü.
+                                               // - 'Vec': Kayıtlarda adres listeleri (örneğin koruyucular) tutmak için kullanılır.
+                                               // - 'BytesN': Zaman kilitli yükseltmelerde Wasm kod özetini (hash) tutmak için kullanılır.
 
 // Sabitler, depolama girişlerinin Yaşam Süresi (TTL) yönetimi için kullanılır.
 // Soroban'da depolama ücretlendirilir ve verilerin aktif tutulması için periyodik olarak TTL'lerinin
@@ -54,16 +56,242 @@ pub struct AllowanceValue {   // Harcama izninin (allowance) değerini tutan yap
     pub expiration_ledger: u32, // İznin son geçerli olacağı defter (ledger) numarası.
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FreezeInfo {          // Bir hesabın neden, kim tarafından ve ne zamana kadar dondurulduğunu tutan kayıt.
+    pub reason: u32,             // Dondurmanın neden kodu (örneğin yaptırım listesi, mahkeme kararı); anlamı ihraççı tarafından tanımlanır.
+    pub authority: Address,      // Dondurmayı yapan yetkili (dondurma anındaki yönetici).
+    pub frozen_at_ledger: u32,   // Hesabın dondurulduğu defter numarası.
+    pub unfreeze_at_ledger: Option<u32>, // Dondurmanın kendiliğinden kalkacağı defter; 'None' ise süresizdir.
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Stream {              // Gönderenden alıcıya defter (ledger) başına sabit bir hızla akan ödeme akışının kaydı.
+    pub sender: Address,         // Akışı oluşturan ve fonu emanete (escrow) yatıran adres.
+    pub recipient: Address,      // Akan tokenları çekebilecek olan alıcı adres.
+    pub rate_per_ledger: i128,   // Her defterde alıcıya tahakkuk eden token miktarı.
+    pub start_ledger: u32,       // Akışın başladığı defter numarası.
+    pub stop_ledger: u32,        // Akışın sona erdiği defter numarası.
+    pub deposit: i128,           // Akış için emanete alınan toplam miktar (rate_per_ledger * (stop_ledger - start_ledger)).
+    pub withdrawn: i128,         // Alıcının şimdiye kadar çektiği toplam miktar.
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Hold {                // Bir hesabın bakiyesinin bir kısmını operatör adına ayıran rezervasyon (hold) kaydı.
+    pub from: Address,           // Bakiyesi rezerve edilen hesap.
+    pub operator: Address,       // Rezervasyonu tahsil edebilecek (execute) veya serbest bırakabilecek (release) adres.
+    pub amount: i128,            // Rezervasyonda kalan (henüz tahsil edilmemiş) miktar.
+    pub expiration_ledger: u32,  // Rezervasyonun geçerli olduğu son defter; sonrasında otomatik olarak serbest kalır.
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AccessMode {            // Token'ın kimlerin token tutabileceğini belirleyen erişim modu.
+    Open,                        // Herkes token tutabilir ve alabilir; yalnızca dondurulmuş hesaplar engellenir.
+    Allowlist,                   // Yalnızca izin listesindeki (KYC doğrulanmış) hesaplar token tutabilir ve alabilir.
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Role {                  // Yöneticinin belirli yetkileri devredebileceği roller.
+    KycOperator,                 // İzin listesine hesap ekleyip çıkarabilen KYC operatörü.
+    ClaimIssuer,                 // Hesaplara kimlik beyanı (claim) verip geri alabilen beyan yayıncısı.
+    MetadataManager,             // Token adını, sembolünü ve genişletilmiş meta verilerini güncelleyebilen meta veri yöneticisi.
+    IndexOracle,                 // Pay tabanlı muhasebede endeksi güncelleyebilen endeks oracle'ı.
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ClaimTopic {            // Bir kimlik beyanının (claim) hangi özelliği tanımladığı.
+    Country,                     // Hesabın ülkesi (değer: ISO 3166-1 sayısal ülke kodu).
+    InvestorType,                // Yatırımcı türü (değer: ihraççının tanımladığı tür kodu).
+    Accredited,                  // Nitelikli (accredited) yatırımcı beyanı.
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Claim {               // Bir beyan yayıncısının bir hesap hakkında verdiği, türü belirli kimlik beyanı.
+    pub topic: ClaimTopic,       // Beyanın konusu.
+    pub value: u32,              // Beyanın değeri (örneğin ülke kodu); anlamı konuya göre değişir.
+    pub issuer: Address,         // Beyanı veren yayıncı.
+    pub expiration_ledger: Option<u32>, // Beyanın geçerli olduğu son defter; 'None' ise süresizdir.
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AdminOp {               // Yönetici işlemleri. Zaman kilitli olanlar, zaman kilidi etkinken planlanıp beklendikten sonra
+                                 // yürütülür; yönetici konseyi ise tüm işlemleri bu tür üzerinden onaylar.
+    SetAdmin(Address),           // Yöneticiyi değiştirir ('set_admin'); varsa yönetici konseyini kaldırır. Zaman kilitli.
+    SetMaxHolders(Option<u32>),  // En fazla token sahibi sayısını değiştirir ('set_max_holders'). Zaman kilitli.
+    SetMaxBalancePerAccount(Option<i128>), // Hesap başına en fazla bakiyeyi değiştirir ('set_max_balance_per_account'). Zaman kilitli.
+    SetCompliance(Option<Address>), // Uyumluluk kontratını değiştirir ('set_compliance'). Zaman kilitli.
+    Upgrade(BytesN<32>),         // Kontrat kodunu yükseltir ('upgrade'). Zaman kilitli.
+    SetTimelockDelay(u32),       // Zaman kilidinin en az bekleme süresini değiştirir ('set_timelock_delay'). Zaman kilitli.
+    SetAdminCouncil(Vec<Address>, u32), // Yönetici konseyinin üyelerini ve onay eşiğini ayarlar ('set_admin_council'). Zaman kilitli.
+    RenounceAdmin,               // Yöneticiyi kalıcı olarak bırakır ('renounce_admin'). Zaman kilitli.
+    Mint(Address, i128),         // Token basar ('mint').
+    FreezeAccount(Address, u32, Option<u32>), // Hesabı neden kodu ve çözülme defteriyle dondurur ('freeze_account').
+    UnfreezeAccount(Address),    // Hesabın dondurulmasını kaldırır ('unfreeze_account').
+    FreezeAccounts(Vec<Address>, u32, Option<u32>), // Birden çok hesabı dondurur ('freeze_accounts').
+    UnfreezeAccounts(Vec<Address>), // Birden çok hesabın dondurulmasını kaldırır ('unfreeze_accounts').
+    FreezeAmount(Address, i128), // Hesabın bakiyesinde bir miktarı dondurur ('freeze_amount').
+    UnfreezeAmount(Address, i128), // Hesabın dondurulmuş miktarından bir kısmını çözer ('unfreeze_amount').
+    ForceTransfer(Address, Address, i128, u32), // Zorunlu transfer yapar ('force_transfer').
+    SetTreasury(Address),        // Hazine adresini ayarlar ('set_treasury').
+    GrantRole(Role, Address),    // Bir adrese rol atar ('grant_role').
+    RevokeRole(Role, Address),   // Bir adresten rolü geri alır ('revoke_role').
+    SetRequiredClaims(Vec<ClaimTopic>), // Alıcıda gereken beyan konularını ayarlar ('set_required_claims').
+    SetFlashLoanConfig(u32, i128), // Anlık kredi ücretini (baz puan) ve en fazla miktarını ayarlar ('set_flash_loan_config').
+    SetFlashLender(Option<Address>), // Anlık kredi kontratını ayarlar veya kaldırır ('set_flash_lender').
+    AddRewardToken(Address),     // Bir ödül token'ını kaydeder ('add_reward_token').
+    RemoveRewardToken(Address),  // Bir ödül token'ını kayıttan çıkarır ('remove_reward_token').
+    CreateAirdrop(BytesN<32>, i128, u32), // Merkle kökü, toplam miktar ve son talep defteriyle bir airdrop oluşturur ('create_airdrop').
+    Migrate(Vec<Address>),       // Verilen hesapların depolamasını güncel düzene taşır ('migrate').
+    FinishMigration,             // Depolama geçişini tamamlar ve yeni sürümü yazar ('finish_migration').
+    ExecuteScheduled(u32),       // Zaman kilidi kuyruğundaki bir işlemi yürütür ('execute').
+    CancelScheduled(u32),        // Zaman kilidi kuyruğundaki bir işlemi iptal eder ('cancel').
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ScheduledOp {         // Zaman kilidi kuyruğunda bekleyen bir yönetici işlemi.
+    pub id: u32,                 // İşlemin kimliği.
+    pub op: AdminOp,             // Yürütülecek işlem.
+    pub eta_ledger: u32,         // İşlemin yürütülebileceği ilk defter.
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AdminCouncil {        // Tek bir yönetici adresinin yerini alan, kontrat üzerinde tutulan M-of-N imzacı kümesi.
+    pub members: Vec<Address>,   // Konsey üyeleri.
+    pub threshold: u32,          // Bir yönetici işlemi için gereken en az üye imzası/onayı sayısı (M).
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Proposal {            // Konsey üyelerinin farklı zamanlarda onayladığı, bekleyen bir yönetici işlemi önerisi.
+    pub id: u32,                 // Önerinin kimliği.
+    pub op: AdminOp,             // Onaylandığında yürütülecek işlem.
+    pub approvals: Vec<Address>, // Öneriyi onaylayan üyeler (öneren üye dahil).
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GuardianConfig {      // Bir hesap sahibinin sosyal kurtarma için belirlediği koruyucular (guardian).
+    pub guardians: Vec<Address>, // Kurtarma önerip onaylayabilen koruyucu adresleri.
+    pub threshold: u32,          // Kurtarmanın yürütülebilmesi için gereken en az koruyucu onayı sayısı.
+    pub delay_ledgers: u32,      // Onay eşiğine ulaşıldıktan sonra, sahibin iptal edebilmesi için beklenen defter sayısı.
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Recovery {            // Bir hesap için koruyucular tarafından önerilen, bekleyen kurtarma kaydı.
+    pub new_owner: Address,      // Bakiye ve harcama izinlerinin taşınacağı yeni adres.
+    pub approvals: Vec<Address>, // Öneriyi onaylayan koruyucular (öneren koruyucu dahil).
+    pub executable_at_ledger: Option<u32>, // Kurtarmanın yürütülebileceği ilk defter; onay eşiğine ulaşılınca belirlenir.
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Airdrop {             // Merkle ağacı tabanlı bir airdrop kaydı.
+    pub root: BytesN<32>,        // Tüm tahsisleri (index, hesap, miktar) kapsayan Merkle kökü.
+    pub total: i128,             // Airdrop ile dağıtılabilecek toplam miktar.
+    pub claimed: i128,           // Şimdiye kadar emanetten ödenen (talep edilen veya süre dolduktan sonra hazineye iade edilen) miktar.
+    pub expiration_ledger: u32,  // Taleplerin kabul edildiği son defter; sonrasında kalan miktar hazineye iade edilebilir.
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MerkleProof {         // Bir airdrop yaprağının Merkle kökünde yer aldığını gösteren kanıt.
+    pub index: u32,              // Yaprağın ağaçtaki sırası; her seviyede düğümün solda mı sağda mı olduğunu belirler.
+    pub siblings: Vec<BytesN<32>>, // Yapraktan köke doğru, her seviyedeki kardeş düğümün özeti.
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FlashLoan {           // Kredi kontratının bastırdığı ve henüz geri çekilmemiş bir anlık kredi.
+    pub amount: i128,            // Alıcıya basılan anapara.
+    pub fee: i128,               // Anaparayla birlikte geri çekilecek ücret.
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RewardAccount {       // Bir hesabın bir ödül token'ı için ödül kaydı (ödül borcu).
+    pub paid_per_share: i128,    // Hesabın en son tahakkuk ettirildiği pay başına kümülatif ödül.
+    pub accrued: i128,           // Tahakkuk etmiş ve henüz talep edilmemiş ödül.
+}
+
+// 'DataKey' Soroban'ın izin verdiği en fazla varyant sayısına ulaştığı için ödüllere ait ek anahtarlar ayrı tutulur.
+#[derive(Clone)]
+#[contracttype]
+pub enum RewardDataKey {         // Ödül dağıtımına ait ek depolama anahtarları.
+    Retired(Address),            // Kayıttan çıkarılmış bir ödül token'ının son pay başına kümülatif ödülü için anahtar.
+    RetiredTokens,               // Kayıttan çıkarılmış (hesapları hâlâ tahakkuk ettirilen) ödül token'larının listesi için anahtar.
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum EscrowDataKey {         // Kontratın emanetine ait ek depolama anahtarları ('RewardDataKey' ile aynı nedenle ayrıdır).
+    Total,                       // Kontratın bakiyesinde ödenmek üzere ayrılmış (akışlar ve airdrop'lar) toplam miktar için anahtar.
+}
+
 #[derive(Clone)] // Bu enum için 'Clone' trait'ini otomatik olarak uygular.
 #[contracttype]  // Bu enum'ın Soroban kontratlarında depolanabilir bir tür olduğunu belirtir.
 pub enum DataKey {            // Kontratın depolamasında kullanılan farklı veri türleri için anahtarları tanımlayan bir enum.
                               // Bu, depolamadaki verileri organize etmeye ve ayırt etmeye yardımcı olur.
     Allowance(AllowanceDataKey), // Bir harcama izni (allowance) verisi için anahtar. 'AllowanceDataKey' yapısını içerir.
-    Balance(Address),            // Bir adresin token bakiyesi için anahtar. İlgili adresi içerir.
+    Balance(Address),            // Bir adresin token payı için anahtar (endeks 1.0 iken pay, bakiyeye eşittir). İlgili adresi içerir.
     Nonce(Address),              // (Bu token kontratında doğrudan kullanılmıyor gibi görünüyor ama genel bir DataKey olabilir)
                                  // Bir adres için nonce (tek kullanımlık sayı, genellikle işlem tekrarını önlemek için) değeri için anahtar.
     State(Address),              // (Bu token kontratında doğrudan kullanılmıyor gibi görünüyor ama genel bir DataKey olabilir)
                                  // Bir adresle ilişkili genel bir durum (state) verisi için anahtar.
     Admin,                       // Kontratın yönetici (administrator) adresi için anahtar. Herhangi bir veri içermez, sadece anahtarın kendisi önemlidir.
-    Frozen(Address),             // Bir hesabın dondurulmuş olup olmadığını belirten durum için anahtar. İlgili adresi içerir.
+    Frozen(Address),             // Bir hesabın dondurma kaydı ('FreezeInfo') için anahtar. İlgili adresi içerir.
+                                 // Sürüm 2'den itibaren 'persistent' depolamada tutulur; eski kayıtlar 'instance' depolamadadır.
+    StreamCount,                 // Şimdiye kadar oluşturulan ödeme akışı (stream) sayısı için anahtar. Yeni akış kimlikleri bundan üretilir.
+    Stream(u32),                 // Belirli bir kimliğe sahip ödeme akışının kaydı ('Stream') için anahtar.
+    HoldCount,                   // Şimdiye kadar oluşturulan bakiye rezervasyonu (hold) sayısı için anahtar.
+    Hold(u32),                   // Belirli bir kimliğe sahip rezervasyonun kaydı ('Hold') için anahtar.
+    AccountHolds(Address),       // Bir hesabın üzerindeki rezervasyon kimliklerinin listesi için anahtar.
+    HolderCount,                 // Bakiyesi sıfırdan büyük olan hesapların (token sahiplerinin) sayısı için anahtar.
+    Holder(u32),                 // Sahip kaydındaki belirli bir sıradaki (index) adres için anahtar.
+    HolderIndex(Address),        // Bir adresin sahip kaydındaki sırası (index) için anahtar.
+    Treasury,                    // Token ihraççısının hazine (treasury) adresi için anahtar. Hazine, sahip/bakiye sınırlarından muaftır.
+    MaxHolders,                  // İzin verilen en fazla token sahibi sayısı için anahtar.
+    MaxBalancePerAccount,        // Tek bir hesabın tutabileceği en fazla bakiye için anahtar.
+    Role(Role, Address),         // Bir adresin belirli bir role (örneğin KYC operatörü) sahip olup olmadığı için anahtar.
+    AccessMode,                  // Token'ın erişim modu ('AccessMode') için anahtar.
+    Allowed(Address),            // İzin listesindeki (allowlist) bir hesabın kaydı ve isteğe bağlı son geçerlilik defteri için anahtar.
+    Compliance,                  // Transfer kurallarını denetleyen harici uyumluluk (compliance) kontratının adresi için anahtar.
+    Claims(Address),             // Bir hesabın kimlik beyanlarının (claim) konuya göre eşlemesi için anahtar.
+    RequiredClaims,              // Transferlerde alıcının sahip olması gereken beyan konularının listesi için anahtar.
+    FrozenAmount(Address),       // Bir hesabın bakiyesinde kısmen dondurulmuş (harcanamaz) miktar için anahtar.
+    Spenders(Address),           // Bir hesabın harcama izni verdiği adreslerin listesi için anahtar. İzinlerin taşınabilmesi için tutulur.
+    Guardians(Address),          // Bir hesabın sosyal kurtarma koruyucuları ayarları ('GuardianConfig') için anahtar.
+    Recovery(Address),           // Bir hesap için bekleyen sosyal kurtarma önerisi ('Recovery') için anahtar.
+    Version,                     // Kontratın depolama düzeninin sürümü için anahtar. Yoksa sürüm 1 kabul edilir.
+    TimelockDelay,               // Zaman kilitli yönetici işlemlerinin planlanması ile yürütülmesi arasındaki en az defter sayısı için anahtar.
+    OpCount,                     // Şimdiye kadar planlanan yönetici işlemi sayısı için anahtar. Yeni işlem kimlikleri bundan üretilir.
+    ScheduledOp(u32),            // Belirli bir kimliğe sahip planlanmış yönetici işleminin kaydı ('ScheduledOp') için anahtar.
+    PendingOps,                  // Bekleyen (yürütülmemiş ve iptal edilmemiş) planlanmış işlem kimliklerinin listesi için anahtar.
+    AdminCouncil,                // Yöneticinin yerini alan çok imzalı yönetici konseyi ('AdminCouncil') için anahtar.
+    ProposalCount,               // Şimdiye kadar oluşturulan konsey önerisi sayısı için anahtar. Yeni öneri kimlikleri bundan üretilir.
+    Proposal(u32),               // Belirli bir kimliğe sahip konsey önerisinin kaydı ('Proposal') için anahtar.
+    AdminRenounced,              // Yöneticinin kalıcı olarak bırakıldığını (token'ın değiştirilemez olduğunu) işaretleyen anahtar.
+    MetadataField(Symbol),       // Genişletilmiş meta veri alanının (örneğin 'icon', 'home_domain', 'description') değeri için anahtar.
+    Underlying,                  // Sarmalayıcı modunda 1:1 sarılan alttaki token'ın adresi için anahtar.
+    FlashFeeBps,                 // Anlık kredi (flash loan) ücreti (baz puan) için anahtar.
+    MaxFlashLoan,                // Tek bir anlık kredinin en fazla miktarı için anahtar.
+    FlashLender,                 // Anlık kredileri yürüten kredi kontratının adresi için anahtar.
+    FlashLoan(Address),          // Bir alıcının geri ödenmemiş anlık kredisi ('FlashLoan') için anahtar (geçici depolama).
+    Index,                       // Pay tabanlı muhasebede bir payın token değerini veren endeks için anahtar.
+    TotalShares,                 // Tüm hesapların toplam payı için anahtar.
+    RewardTokens,                // Kayıtlı ödül token'larının listesi için anahtar.
+    RewardPerShare(Address),     // Bir ödül token'ı için pay başına kümülatif ödül için anahtar.
+    RewardAccount(Address, Address), // Bir hesabın (ilk adres) bir ödül token'ı (ikinci adres) için ödül kaydı ('RewardAccount') için anahtar.
+    AirdropCount,                // Şimdiye kadar oluşturulan airdrop sayısı için anahtar. Yeni airdrop kimlikleri bundan üretilir.
+    Airdrop(u32),                // Belirli bir kimliğe sahip airdrop'un kaydı ('Airdrop') için anahtar.
+    AirdropClaimed(u32, u32),    // Bir airdrop'un talep bit haritasındaki 128 bitlik bir kelime (airdrop kimliği, kelime sırası) için anahtar.
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+// Bu dosya (stream.rs), 'soroban-token-contract' kütüphanesinin 'stream' modülünü oluşturur.
+// Bu modül, bir hesaptan diğerine defter (ledger) başına sabit bir hızla akan ödeme akışlarını (stream) yönetir.
+// Akış oluşturulurken toplam miktar gönderenin bakiyesinden düşülür ve akış kaydında emanette (escrow) tutulur;
+// ayrı bir emanet kontratına gerek yoktur. Alıcı, tahakkuk eden kısmı istediği zaman çekebilir.
+// Akış kayıtları 'persistent' (kalıcı) depolamada, akış sayacı ise 'instance' depolamada saklanır.
+
+use crate::storage_types::{DataKey, Stream, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::Env;
+
+// 'next_stream_id' fonksiyonu, yeni bir akış için benzersiz bir kimlik üretir ve sayacı bir artırır.
+pub fn next_stream_id(e: &Env) -> u32 {
+    let key = DataKey::StreamCount;                                    // Akış sayacının depolama anahtarı.
+    let id = e.storage().instance().get::<_, u32>(&key).unwrap_or(0); // Mevcut sayaç değeri (ilk akış için 0).
+    e.storage().instance().set(&key, &(id + 1));                      // Sayacı bir sonraki akış için artır.
+    id                                                                 // Üretilen kimliği döndür.
+}
+
+// 'read_stream' fonksiyonu, verilen kimliğe sahip akış kaydını okur.
+// Akış bulunamazsa (hiç oluşturulmamış veya tamamen kapanmışsa) program panikler.
+pub fn read_stream(e: &Env, id: u32) -> Stream {
+    let key = DataKey::Stream(id);
+    if let Some(stream) = e.storage().persistent().get::<DataKey, Stream>(&key) {
+        // Kayıt bulunduysa, kalıcı depolamada canlı kalması için TTL'sini uzatırız.
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        stream
+    } else {
+        panic!("stream not found")
+    }
+}
+
+// 'write_stream' fonksiyonu, akış kaydını kalıcı depolamaya yazar ve TTL'sini uzatır.
+pub fn write_stream(e: &Env, id: u32, stream: &Stream) {
+    let key = DataKey::Stream(id);
+    e.storage().persistent().set(&key, stream);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// 'remove_stream' fonksiyonu, tamamen ödenmiş veya iptal edilmiş bir akışın kaydını depolamadan siler.
+pub fn remove_stream(e: &Env, id: u32) {
+    e.storage().persistent().remove(&DataKey::Stream(id));
+}
+
+// 'streamed_amount' fonksiyonu, mevcut deftere kadar alıcıya tahakkuk eden toplam miktarı hesaplar.
+// Akış başlamadan önce 0, bittikten sonra ise yatırılan miktarın tamamıdır.
+pub fn streamed_amount(e: &Env, stream: &Stream) -> i128 {
+    let now = e.ledger().sequence();                             // Mevcut defter numarası.
+    let elapsed = now.clamp(stream.start_ledger, stream.stop_ledger) - stream.start_ledger; // Akışın geçen süresi.
+    stream.rate_per_ledger * i128::from(elapsed)                 // Geçen süre boyunca akan miktar.
+}
+
+// 'withdrawable_amount' fonksiyonu, alıcının şu anda çekebileceği (tahakkuk etmiş ama çekilmemiş) miktarı döndürür.
+pub fn withdrawable_amount(e: &Env, stream: &Stream) -> i128 {
+    streamed_amount(e, stream) - stream.withdrawn
+}
@@ -10,19 +10,27 @@ extern crate std; // Testlerde standart kütüphanenin bazı özelliklerini (ör
                   // 'std' kütüphanesini dışarıdan (extern) alır. Normalde '#![no_std]' ile derlenen kontratta
                   // bu olmaz, ancak test ortamında genellikle standart kütüphane kullanılabilir.
 
-use crate::{contract::Token, TokenClient}; // Mevcut kütüphaneden (crate) 'Token' kontrat yapısını ve 'TokenClient' istemcisini içeri aktarır.
+use crate::{contract::Token, LimitError, MetadataError, TokenClient}; // Mevcut kütüphaneden (crate) 'Token' kontrat yapısını ve 'TokenClient' istemcisini içeri aktarır.
+use crate::storage_types::{AccessMode, AdminOp, ClaimTopic, DataKey, MerkleProof, Role}; // Erişim modu, beyan konusu ve rol enum'larını, depolama anahtarlarını içeri aktarır.
+use crate::airdrop::airdrop_leaf; // Airdrop yapraklarının özetini testteki Merkle ağacı için hesaplar.
+use crate::upgrade::CONTRACT_VERSION; // Kontratın güncel depolama sürümünü içeri aktarır.
                                            // 'TokenClient', kontrat fonksiyonlarını testlerde daha kolay çağırmak için kullanılır.
 use soroban_sdk::{
     symbol_short, // Kısa semboller ('Symbol' türü) oluşturmak için bir yardımcı makro. Genellikle fonksiyon adları için kullanılır.
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation}, // Soroban SDK'sının test yardımcı araçları:
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger}, // Soroban SDK'sının test yardımcı araçları:
                                                                         // 'Address as _': Adres oluşturma gibi testlere özel Address fonksiyonlarını getirir.
                                                                         // 'AuthorizedFunction': Bir kontrat fonksiyonunun yetkilendirilmiş çağrısını temsil eder.
                                                                         // 'AuthorizedInvocation': Yetkilendirilmiş bir çağrının tüm detaylarını (fonksiyon, alt çağrılar) tutar.
-    Address, Env, IntoVal, Symbol, // Soroban SDK'sından temel türler:
-                                   // 'Address': Adres türü.
-                                   // 'Env': Test için sanal bir çalışma ortamı (environment).
-                                   // 'IntoVal': Rust türlerini Soroban'ın 'Val' türüne dönüştürmek için bir trait.
-                                   // 'Symbol': Sembol türü (kısa stringler).
+                                                                        // 'Ledger': Testlerde defter (ledger) numarasını ilerletmek için kullanılır.
+                                                                        // 'Events': Testlerde yayınlanan olayları (events) okumak için kullanılır.
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec, // Soroban SDK'sından temel türler:
+                                                // 'Address': Adres türü.
+                                                // 'Env': Test için sanal bir çalışma ortamı (environment).
+                                                // 'IntoVal': Rust türlerini Soroban'ın 'Val' türüne dönüştürmek için bir trait.
+                                                // 'Symbol': Sembol türü (kısa stringler).
+                                                // 'Vec': Toplu işlemlere adres listesi vermek için kullanılan vektör türü.
+                                                // 'BytesN': Sabit uzunluklu bayt dizisi (örneğin Wasm kod özeti).
+                                                // 'Bytes': Değişken uzunluklu bayt dizisi (örneğin Merkle düğümü girdisi).
 };
 
 // 'create_token' yardımcı fonksiyonu, testler için yeni bir token kontratı örneği oluşturur ve başlatır.
@@ -32,16 +40,59 @@ fn create_token<'a>(e: &Env, admin: &Address) -> TokenClient<'a> {
     // Fonksiyon, başlatılmış bir 'TokenClient' örneği döndürür.
 
     // Yeni bir 'TokenClient' oluşturur.
-    // '&e.register_contract(None, Token {})' satırı, 'Token' kontratını test ortamına kaydeder
-    // ve kontratın adresini döndürür. Bu adres, 'TokenClient'ı başlatmak için kullanılır.
-    let token = TokenClient::new(e, &e.register_contract(None, Token {}));
-    // Oluşturulan token kontratını 'initialize' fonksiyonu ile başlatır.
-    // Parametreler: yönetici, ondalık sayısı (7), token adı ("name"), token sembolü ("symbol").
-    // '".into_val(e)"' ile Rust string'leri Soroban 'Val' türüne dönüştürülür.
-    token.initialize(admin, &7, &"name".into_val(e), &"symbol".into_val(e));
+    // 'e.register(Token, ...)' satırı, 'Token' kontratını test ortamına kaydeder, kurucusunu ('__constructor')
+    // verilen argümanlarla aynı işlemde çalıştırır ve kontratın adresini döndürür.
+    // Parametreler: yönetici, ondalık sayısı (7), token adı ("name"), token sembolü ("symbol"), erişim modu (açık).
+    // '".into_val(e)"' ile Rust string'leri Soroban 'String' türüne dönüştürülür.
+    let token = TokenClient::new(
+        e,
+        &e.register(
+            Token,
+            (
+                admin.clone(),
+                7_u32,
+                String::from_str(e, "name"),
+                String::from_str(e, "symbol"),
+                AccessMode::Open,
+                None::<Address>,
+            ),
+        ),
+    );
     token // Başlatılmış 'TokenClient'ı döndür.
 }
 
+// 'build_merkle_tree' yardımcı fonksiyonu, airdrop testleri için verilen yapraklardan bir Merkle ağacı kurar ve
+// kökü ile her yaprağın kanıtını (yapraktan köke kardeş düğümler) döndürür. Tek sayıda düğüm içeren seviyelerde
+// son düğüm kendisiyle eşleştirilir.
+fn build_merkle_tree(e: &Env, leaves: &[BytesN<32>]) -> (BytesN<32>, std::vec::Vec<Vec<BytesN<32>>>) {
+    let mut levels = std::vec![leaves.to_vec()];
+    while levels.last().unwrap().len() > 1 {
+        let level = levels.last().unwrap();
+        let parents = level
+            .chunks(2)
+            .map(|pair| {
+                let mut data = Bytes::new(e);
+                data.append(&pair[0].clone().into());
+                data.append(&pair.get(1).unwrap_or(&pair[0]).clone().into());
+                e.crypto().sha256(&data).to_bytes()
+            })
+            .collect();
+        levels.push(parents);
+    }
+    let proofs = (0..leaves.len())
+        .map(|leaf| {
+            let mut proof = Vec::new(e);
+            let mut index = leaf;
+            for level in &levels[..levels.len() - 1] {
+                proof.push_back(level.get(index ^ 1).unwrap_or(&level[index]).clone());
+                index /= 2;
+            }
+            proof
+        })
+        .collect();
+    (levels.last().unwrap()[0].clone(), proofs)
+}
+
 #[test] // Bu fonksiyonun bir test senaryosu olduğunu belirtir.
 fn test() { // Genel işlevselliği test eden bir test fonksiyonu.
     let e = Env::default(); // Varsayılan ayarlarla yeni bir test çalışma ortamı ('Env') oluşturur.
@@ -286,31 +337,1678 @@ fn transfer_from_insufficient_allowance() { // Yetersiz harcama izni durumunda '
 }
 
 #[test]
-#[should_panic(expected = "already initialized")] // Beklenen panik mesajı.
-fn initialize_already_initialized() { // Kontrat zaten başlatılmışken tekrar 'initialize' çağrılmasının paniklemesini test eder.
+#[should_panic(expected = "Decimal must fit in a u8")] // Beklenen panik mesajı.
+fn decimal_is_over_max() { // Ondalık sayısının u8 sınırını aşması durumunda kurucunun paniklemesini test eder.
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    // Kontratı, u8'in maksimum değerinden (255) büyük bir ondalık sayısıyla dağıtmaya çalışır.
+    // u32::from(u8::MAX) + 1, 256 değerini verir.
+    // Bu işlemin "Decimal must fit in a u8" mesajıyla paniklemesi beklenir.
+    e.register(
+        Token,
+        (
+            admin.clone(),
+            u32::from(u8::MAX) + 1, // Ondalık sayısı (256)
+            String::from_str(&e, "name"),
+            String::from_str(&e, "symbol"),
+            AccessMode::Open,
+            None::<Address>,
+        ),
+    );
+}
+
+#[test] // Kurucuyla dağıtılan bir örneğin hiçbir durumda ayrı bir 'initialize' çağrısıyla ele geçirilemediğini test eder.
+fn initialize_cannot_hijack_constructed_token() {
     let e = Env::default();
+    e.mock_all_auths();
+
     let admin = Address::generate(&e);
-    let token = create_token(&e, &admin); // Kontrat oluşturulur ve 'create_token' içinde zaten başlatılır.
+    let attacker = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    assert_eq!(token.admin(), Some(admin.clone()));
+
+    // Dağıtımdan hemen sonra, yönetici bırakıldıktan sonra ve kurucu dışında hiçbir başlatma yolu yoktur.
+    assert!(!initialize_succeeds(&e, &token.address, &attacker));
+    assert_eq!(token.admin(), Some(admin.clone()));
+    assert_eq!(token.name(), "name".into_val(&e));
 
-    // Zaten başlatılmış olan kontratı tekrar başlatmaya çalışır.
-    // Bu işlemin "already initialized" mesajıyla paniklemesi beklenir.
-    token.initialize(&admin, &10, &"name".into_val(&e), &"symbol".into_val(&e));
+    // Basım hâlâ yalnızca gerçek yöneticinin imzasını ister.
+    token.mint(&attacker, &1);
+    assert_eq!(e.auths()[0].0, admin);
+
+    token.renounce_admin();
+    assert!(!initialize_succeeds(&e, &token.address, &attacker));
+    assert_eq!(token.admin(), None);
+}
+
+// 'initialize_succeeds' yardımcı fonksiyonu, kontratta eski 'initialize' fonksiyonunu çağırmayı dener
+// ve çağrının başarılı olup olmadığını döndürür.
+fn initialize_succeeds(e: &Env, token: &Address, admin: &Address) -> bool {
+    let args = (
+        admin.clone(),
+        7_u32,
+        String::from_str(e, "evil"),
+        String::from_str(e, "EVIL"),
+        AccessMode::Open,
+        None::<Address>,
+    );
+    matches!(
+        e.try_invoke_contract::<(), soroban_sdk::InvokeError>(token, &Symbol::new(e, "initialize"), args.into_val(e)),
+        Ok(Ok(()))
+    )
+}
+
+#[test] // Ödeme akışı (stream) oluşturma, çekim ve iptal işlevselliğini test eder.
+fn test_stream() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let sender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&sender, &1000);
+    e.ledger().set_sequence_number(100);
+
+    // 100. defterden 200. deftere kadar defter başına 5 token akan bir akış oluştur (toplam 500).
+    let id = token.create_stream(&sender, &recipient, &5, &100, &200, &500);
+    // Emanet, kontratın adresine yapılmış standart bir 'transfer' olayı olarak görünür.
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("transfer"), sender.clone(), token.address.clone()).into_val(&e));
+    let data: i128 = data.into_val(&e);
+    assert_eq!(data, 500);
+    assert_eq!(token.balance(&sender), 500); // Emanete alınan 500 token göndericinin bakiyesinden düşülür
+    assert_eq!(token.balance(&token.address), 500); // ve kontratın bakiyesine eklenir.
+    assert_eq!(token.stream(&id).deposit, 500);
+
+    // 40 defter sonra alıcıya 200 token tahakkuk etmiştir; bunun 150'sini çeker.
+    e.ledger().set_sequence_number(140);
+    token.withdraw_from_stream(&id, &150);
+    assert_eq!(token.balance(&recipient), 150);
+    assert_eq!(token.stream(&id).withdrawn, 150);
+    assert_eq!(token.balance(&token.address), 350);
+
+    // 60. defterde (300 tahakkuk) gönderen akışı iptal eder: alıcı kalan 150'yi, gönderen akmamış 200'ü alır.
+    e.ledger().set_sequence_number(160);
+    token.cancel_stream(&sender, &id);
+    assert_eq!(token.balance(&recipient), 300);
+    assert_eq!(token.balance(&sender), 700);
+    assert_eq!(token.balance(&token.address), 0); // Emanet tamamen dağıtılmıştır.
+    assert!(token.try_stream(&id).is_err()); // İptal edilen akışın kaydı silinmiştir.
+
+    // Akışlar transferle aynı kontrollerden geçer: alıcıda gerekli beyanlar yoksa akış oluşturulamaz.
+    token.set_required_claims(&soroban_sdk::vec![&e, ClaimTopic::Accredited]);
+    assert!(token.try_create_stream(&sender, &recipient, &5, &200, &300, &500).is_err());
+    token.freeze_account(&sender, &1, &None);
+    assert!(token.try_create_stream(&sender, &sender, &5, &200, &300, &500).is_err());
 }
 
 #[test]
-#[should_panic(expected = "Decimal must fit in a u8")] // Beklenen panik mesajı.
-fn decimal_is_over_max() { // Ondalık sayısının u8 sınırını aşması durumunda 'initialize'ın paniklemesini test eder.
+#[should_panic(expected = "amount exceeds withdrawable stream balance")] // Beklenen panik mesajı.
+fn stream_withdraw_more_than_streamed() { // Alıcının henüz akmamış tutarı çekmeye çalışmasının paniklemesini test eder.
     let e = Env::default();
+    e.mock_all_auths();
+
     let admin = Address::generate(&e);
-    // TokenClient'ı doğrudan oluşturur, 'create_token' gibi otomatik başlatma yapmaz.
-    let token = TokenClient::new(&e, &e.register_contract(None, Token {}));
-    // 'initialize' fonksiyonunu, u8'in maksimum değerinden (255) büyük bir ondalık sayısıyla çağırır.
-    // u32::from(u8::MAX) + 1, 256 değerini verir.
-    // Bu işlemin "Decimal must fit in a u8" mesajıyla paniklemesi beklenir.
-    token.initialize(
-        &admin,
-        &(u32::from(u8::MAX) + 1), // Ondalık sayısı (256)
-        &"name".into_val(&e),
-        &"symbol".into_val(&e),
+    let sender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&sender, &1000);
+    let id = token.create_stream(&sender, &recipient, &10, &0, &100, &1000);
+
+    e.ledger().set_sequence_number(10); // Yalnızca 100 token akmıştır.
+    token.withdraw_from_stream(&id, &101);
+}
+
+#[test] // Bakiye rezervasyonu (hold) oluşturma, kısmi tahsilat, serbest bırakma ve otomatik serbest kalmayı test eder.
+fn test_hold() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let payer = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let merchant = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&payer, &1000);
+
+    // 'payer', 'operator' adına 100. deftere kadar geçerli 600 tokenlık bir rezervasyon oluşturur.
+    let id = token.hold(&payer, &operator, &600, &100);
+    assert_eq!(token.balance(&payer), 1000); // Bakiye toplam olarak kalır.
+    assert_eq!(token.held_balance(&id), 600);
+    assert!(token.try_transfer(&payer, &merchant, &401).is_err()); // Rezerve edilmemiş kısım yalnızca 400'dür.
+
+    // Operatör rezervasyonun 250'sini tahsil eder.
+    token.execute_hold(&id, &merchant, &250);
+    assert_eq!(token.balance(&payer), 750);
+    assert_eq!(token.balance(&merchant), 250);
+    assert_eq!(token.held_balance(&id), 350);
+
+    // Operatör kalanı serbest bırakır; tüm bakiye yeniden harcanabilir.
+    token.release_hold(&id);
+    assert_eq!(token.held_balance(&id), 0);
+    token.transfer(&payer, &merchant, &750);
+    assert_eq!(token.balance(&payer), 0);
+
+    // Süresi dolan rezervasyon kendiliğinden serbest kalır.
+    token.mint(&payer, &500);
+    let id = token.hold(&payer, &operator, &500, &100);
+    assert!(token.try_transfer(&payer, &merchant, &1).is_err());
+    e.ledger().set_sequence_number(101);
+    assert_eq!(token.held_balance(&id), 0);
+    assert!(token.try_execute_hold(&id, &merchant, &1).is_err());
+    token.transfer(&payer, &merchant, &500);
+    assert_eq!(token.balance(&merchant), 1500);
+}
+
+#[test]
+#[should_panic(expected = "amount exceeds held amount")] // Beklenen panik mesajı.
+fn execute_hold_more_than_held() { // Operatörün rezervasyondan fazlasını tahsil etmeye çalışmasının paniklemesini test eder.
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let payer = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&payer, &1000);
+    let id = token.hold(&payer, &operator, &100, &100);
+    token.execute_hold(&id, &operator, &101);
+}
+
+#[test] // Token sahipleri kaydının (holder registry) bakiye değişimleriyle güncellenmesini ve sayfalamayı test eder.
+fn test_holders() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    assert_eq!(token.holder_count(), 0);
+    token.mint(&user1, &100);
+    token.mint(&user2, &100);
+    token.mint(&user3, &100);
+    token.mint(&user1, &100); // Mevcut bir sahibe yapılan basım kaydı değiştirmez.
+    assert_eq!(token.holder_count(), 3);
+    assert_eq!(
+        token.holders(&0, &10),
+        soroban_sdk::vec![&e, user1.clone(), user2.clone(), user3.clone()]
+    );
+
+    // 'user1' tüm bakiyesini devrettiğinde kayıttan çıkar; son sahip onun yerine taşınır.
+    token.transfer(&user1, &user2, &200);
+    assert_eq!(token.holder_count(), 2);
+    assert_eq!(token.holders(&0, &1), soroban_sdk::vec![&e, user3.clone()]);
+    assert_eq!(token.holders(&1, &10), soroban_sdk::vec![&e, user2.clone()]);
+    assert_eq!(token.holders(&5, &10).len(), 0);
+
+    // Sıfır miktarlı transfer alıcıyı sahip yapmaz.
+    token.transfer(&user2, &user1, &0);
+    assert_eq!(token.holder_count(), 2);
+}
+
+#[test] // En fazla sahip sayısı ve hesap başına bakiye sınırlarının basım ve transferlerde uygulanmasını test eder.
+fn test_holder_and_balance_limits() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.set_treasury(&treasury);
+    token.set_max_holders(&Some(2));
+    token.set_max_balance_per_account(&Some(500));
+    assert_eq!(token.treasury(), Some(treasury.clone()));
+    assert_eq!(token.max_holders(), Some(2));
+    assert_eq!(token.max_balance_per_account(), Some(500));
+
+    // Hazine her iki sınırdan da muaftır ve sahip sayısına dahil edilmez.
+    token.mint(&treasury, &10_000);
+    token.transfer(&treasury, &user1, &500);
+    token.transfer(&treasury, &user2, &300);
+    assert_eq!(token.holder_count(), 3);
+
+    // Hesap başına bakiye sınırı hem basımda hem transferde uygulanır.
+    // Sınır hataları tiplidir; istemciler hangi sınırın aşıldığını ayırt edebilir.
+    assert_eq!(
+        token.try_mint(&user2, &201).err().unwrap().unwrap(),
+        LimitError::MaxBalancePerAccountExceeded.into()
+    );
+    assert!(token.try_transfer(&treasury, &user2, &201).is_err());
+    token.transfer(&treasury, &user2, &200);
+
+    // Üçüncü bir sahip sınırı aşar; mevcut sahipler arasında transfer serbesttir.
+    assert_eq!(
+        token.try_transfer(&treasury, &user3, &1).err().unwrap().unwrap(),
+        LimitError::MaxHoldersExceeded.into()
     );
-}
\ No newline at end of file
+    token.transfer(&user1, &user2, &0);
+
+    // Bir sahip çıkınca yeni bir sahibe yer açılır.
+    token.transfer(&user1, &treasury, &500);
+    token.transfer(&treasury, &user3, &1);
+
+    // Sınırlar kaldırılabilir.
+    token.set_max_holders(&None);
+    token.set_max_balance_per_account(&None);
+    token.mint(&user1, &1_000);
+    assert_eq!(token.balance(&user1), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")] // Beklenen tipli hata: 'LimitError::MaxHoldersExceeded'.
+fn mint_over_max_holders() { // Sahip sınırına ulaşıldıktan sonra yeni bir hesaba basım yapılmasının paniklemesini test eder.
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.set_max_holders(&Some(1));
+    token.mint(&user1, &100);
+    token.mint(&user2, &100);
+}
+
+#[test] // İzin listesi (KYC) modunda basım ve transferlerin yalnızca izinli hesaplarla yapılabilmesini test eder.
+fn test_allowlist_mode() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let kyc = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = TokenClient::new(
+        &e,
+        &e.register(
+            Token,
+            (
+                admin.clone(),
+                7_u32,
+                String::from_str(&e, "name"),
+                String::from_str(&e, "symbol"),
+                AccessMode::Allowlist,
+                None::<Address>,
+            ),
+        ),
+    );
+    assert_eq!(token.access_mode(), AccessMode::Allowlist);
+
+    // İzin listesinde olmayan bir hesaba basım yapılamaz.
+    assert!(!token.is_allowed(&user1));
+    assert!(token.try_mint(&user1, &100).is_err());
+
+    // Yönetici bir KYC operatörü atar; operatör hesapları izin listesine ekler.
+    token.grant_role(&Role::KycOperator, &kyc);
+    assert!(token.has_role(&Role::KycOperator, &kyc));
+    token.allow(&kyc, &user1, &None);
+    token.allow(&kyc, &user2, &Some(100));
+    token.mint(&user1, &1000);
+    token.transfer(&user1, &user2, &100);
+
+    // İzinsiz alıcıya transfer ve 'transfer_from' reddedilir.
+    assert!(token.try_transfer(&user1, &user3, &100).is_err());
+    token.approve(&user1, &user2, &500, &200);
+    assert!(token.try_transfer_from(&user2, &user1, &user3, &100).is_err());
+    token.transfer_from(&user2, &user1, &user2, &100);
+
+    // Süresi dolan izin kaydı, hesabın göndermesini de engeller.
+    e.ledger().set_sequence_number(101);
+    assert!(!token.is_allowed(&user2));
+    assert!(token.try_transfer(&user2, &user1, &100).is_err());
+
+    // İzin listesinden çıkarılan alıcıya akış ödemesi yapılmaz; alıcıya ödeme içeren iptal de reddedilir.
+    token.allow(&kyc, &user3, &None);
+    let id = token.create_stream(&user1, &user3, &1, &101, &201, &100);
+    e.ledger().set_sequence_number(151);
+    token.disallow(&kyc, &user3);
+    assert!(token.try_withdraw_from_stream(&id, &10).is_err());
+    assert!(token.try_cancel_stream(&user1, &id).is_err());
+    token.allow(&kyc, &user3, &None);
+    token.withdraw_from_stream(&id, &50);
+
+    // İzni kaldırılan göndericiye akmamış kısım iade edilemez.
+    token.disallow(&kyc, &user1);
+    assert!(token.try_cancel_stream(&user3, &id).is_err());
+
+    // İzni kaldırılan hesap token alamaz.
+    token.disallow(&admin, &user1);
+    assert!(token.try_mint(&user1, &100).is_err());
+
+    // Rolü geri alınan operatör artık izin veremez.
+    token.revoke_role(&Role::KycOperator, &kyc);
+    assert!(token.try_allow(&kyc, &user3, &None).is_err());
+}
+
+#[test] // Token ile örnek uyumluluk kontratının aynı 'Env' içinde birlikte çalışmasını test eder.
+fn test_compliance_contract() {
+    use soroban_compliance_contract::{Compliance, ComplianceClient};
+
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // Uyumluluk kontratını kaydet, token'a bağla ve kuralları ayarla.
+    let compliance = ComplianceClient::new(&e, &e.register(Compliance, (admin.clone(), token.address.clone())));
+    compliance.set_country(&user1, &792);
+    compliance.set_country(&user2, &792);
+    compliance.set_country(&user3, &276);
+    compliance.set_country_holder_cap(&792, &Some(1));
+    token.set_compliance(&Some(compliance.address.clone()));
+    assert_eq!(token.compliance(), Some(compliance.address.clone()));
+
+    // Basım ve transferler uyumluluk kontratına bildirilir.
+    token.mint(&user1, &1000);
+    assert_eq!(compliance.country_holders(&792), 1);
+    token.transfer(&user1, &user3, &100);
+    assert_eq!(compliance.country_holders(&276), 1);
+
+    // Ülke sahip sınırı ve engellenmiş ülke kuralları token tarafından uygulanır.
+    assert!(token.try_transfer(&user1, &user2, &100).is_err());
+    compliance.set_country_blocked(&276, &true);
+    assert!(token.try_transfer(&user3, &user1, &100).is_err());
+    assert!(token.try_mint(&user3, &100).is_err());
+    compliance.set_country_blocked(&276, &false);
+
+    // Yakma bildirimi sahip sayısını günceller ve yeni bir sahibe yer açar.
+    token.burn(&user1, &900);
+    assert_eq!(compliance.country_holders(&792), 0);
+    token.transfer(&user3, &user2, &100);
+    assert_eq!(token.balance(&user2), 100);
+
+    // Kayıt kaldırıldığında kurallar artık uygulanmaz.
+    token.set_compliance(&None);
+    token.mint(&user1, &1);
+}
+
+#[test] // Rezervasyon tahsilatlarının ve ödeme akışlarının uyumluluk kontratını atlayamadığını test eder.
+fn test_compliance_holds_and_streams() {
+    use soroban_compliance_contract::{Compliance, ComplianceClient};
+
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let outsider = Address::generate(&e); // Ülkesi kayıtlı olmayan, token alamayan hesap.
+    let token = create_token(&e, &admin);
+
+    let compliance = ComplianceClient::new(&e, &e.register(Compliance, (admin.clone(), token.address.clone())));
+    compliance.set_country(&user1, &792);
+    compliance.set_country(&user2, &792);
+    compliance.set_country(&user3, &276);
+    token.set_compliance(&Some(compliance.address.clone()));
+    token.mint(&user1, &1000);
+
+    // Kendi adına açılan bir rezervasyonla kurallara takılan bir alıcıya ödeme yapılamaz.
+    let id = token.hold(&user1, &user1, &100, &100);
+    assert!(token.try_execute_hold(&id, &outsider, &100).is_err());
+    token.execute_hold(&id, &user3, &100);
+    assert_eq!(compliance.country_holders(&276), 1);
+
+    // Akış, kurallara takılan bir alıcıya açılamaz; emanet bakiye yansımasından düşülür.
+    assert!(token.try_create_stream(&user1, &outsider, &10, &0, &90, &900).is_err());
+    let id = token.create_stream(&user1, &user2, &10, &0, &90, &900);
+    assert_eq!(compliance.country_holders(&792), 0);
+
+    // Alıcının ülkesi engellenince ne çekim ne de iptal yoluyla ödeme yapılabilir.
+    e.ledger().set_sequence_number(10);
+    compliance.set_country_blocked(&792, &true);
+    assert!(token.try_withdraw_from_stream(&id, &100).is_err());
+    assert!(token.try_cancel_stream(&user1, &id).is_err());
+    compliance.set_country_blocked(&792, &false);
+    token.withdraw_from_stream(&id, &100);
+    assert_eq!(compliance.country_holders(&792), 1);
+}
+
+#[test] // Kimlik beyanlarının (claim) verilmesini, geri alınmasını ve transferlerde alıcıdan gerekli beyanların aranmasını test eder.
+fn test_identity_claims() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let issuer = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.grant_role(&Role::ClaimIssuer, &issuer);
+    token.set_claim(&issuer, &user2, &ClaimTopic::Country, &792, &None);
+    token.set_claim(&issuer, &user2, &ClaimTopic::Accredited, &1, &Some(100));
+    let claims = token.claims_of(&user2);
+    assert_eq!(claims.len(), 2);
+    assert_eq!(claims.get(0).unwrap().issuer, issuer);
+
+    // Alıcıların nitelikli yatırımcı olması gerekir.
+    token.set_required_claims(&soroban_sdk::vec![&e, ClaimTopic::Accredited]);
+    token.mint(&user1, &1000);
+    token.transfer(&user1, &user2, &100);
+    assert!(token.try_transfer(&user2, &user1, &50).is_err()); // 'user1'in beyanı yok.
+
+    // Süresi dolan beyan artık geçerli değildir.
+    e.ledger().set_sequence_number(101);
+    assert_eq!(token.claims_of(&user2).len(), 1);
+    assert!(token.try_transfer(&user1, &user2, &100).is_err());
+
+    // Yenilenen beyan transferi yeniden mümkün kılar; geri alınan beyan kılmaz.
+    token.set_claim(&issuer, &user2, &ClaimTopic::Accredited, &1, &None);
+    token.approve(&user1, &admin, &100, &200);
+    token.transfer_from(&admin, &user1, &user2, &100);
+    token.revoke_claim(&issuer, &user2, &ClaimTopic::Accredited);
+    assert!(token.try_transfer(&user1, &user2, &100).is_err());
+
+    // Rolü geri alınan yayıncının beyanları geçersiz sayılır.
+    token.revoke_role(&Role::ClaimIssuer, &issuer);
+    assert_eq!(token.claims_of(&user2).len(), 0);
+
+    // Rezervasyon tahsilatı ve akış çekimi de alıcının beyanlarını arar.
+    let id = token.hold(&user1, &user1, &100, &200);
+    assert!(token.try_execute_hold(&id, &user2, &100).is_err());
+    token.set_claim(&admin, &user2, &ClaimTopic::Accredited, &1, &None);
+    let stream = token.create_stream(&user1, &user2, &1, &101, &201, &100);
+    token.execute_hold(&id, &user2, &100);
+    token.revoke_claim(&admin, &user2, &ClaimTopic::Accredited);
+    e.ledger().set_sequence_number(111);
+    assert!(token.try_withdraw_from_stream(&stream, &10).is_err());
+    assert!(token.try_cancel_stream(&user2, &stream).is_err());
+}
+
+#[test] // Neden kodlu ve süreli hesap dondurmayı, olay içeriklerini ve otomatik çözülmeyi test eder.
+fn test_freeze_with_reason() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    e.ledger().set_sequence_number(10);
+
+    // 'user1' 7 neden koduyla 100. deftere kadar dondurulur; olay neden kodunu içerir.
+    token.freeze_account(&user1, &7, &Some(100));
+    let (contract, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(contract, token.address);
+    assert_eq!(topics, ("freeze_account", admin.clone(), user1.clone()).into_val(&e));
+    let data: (u32, Option<u32>) = data.into_val(&e);
+    assert_eq!(data, (7, Some(100)));
+    let info = token.freeze_info(&user1).unwrap();
+    assert_eq!(info.reason, 7);
+    assert_eq!(info.authority, admin);
+    assert_eq!(info.frozen_at_ledger, 10);
+    assert!(token.is_frozen(&user1));
+    assert!(token.try_transfer(&user1, &user2, &100).is_err());
+
+    // Çözülme defterine ulaşıldığında dondurma kendiliğinden kalkar.
+    e.ledger().set_sequence_number(100);
+    assert!(!token.is_frozen(&user1));
+    assert_eq!(token.freeze_info(&user1), None);
+    token.transfer(&user1, &user2, &100);
+
+    // Süresiz dondurma yalnızca 'unfreeze_account' ile kalkar; olay kaldırılan neden kodunu içerir.
+    token.freeze_account(&user1, &3, &None);
+    assert!(token.try_burn(&user1, &100).is_err());
+    token.unfreeze_account(&user1);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, ("unfreeze_account", admin.clone(), user1.clone()).into_val(&e));
+    let data: Option<u32> = data.into_val(&e);
+    assert_eq!(data, Some(3));
+    token.burn(&user1, &100);
+    assert_eq!(token.balance(&user1), 800);
+}
+
+#[test] // Belirli bir miktarın dondurulmasını (kısmi dondurma) ve tüm hesap dondurmayla birlikte çalışmasını test eder.
+fn test_freeze_amount() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    token.freeze_amount(&user1, &300);
+    token.freeze_amount(&user1, &100);
+    assert_eq!(token.frozen_amount(&user1), 400);
+
+    // Bakiyenin dondurulmamış kısmı harcanabilir; dondurulmuş miktarın altına inilemez.
+    token.transfer(&user1, &user2, &500);
+    assert!(token.try_transfer(&user1, &user2, &101).is_err());
+    assert!(token.try_burn(&user1, &101).is_err());
+    assert!(token.try_hold(&user1, &operator, &101, &100).is_err());
+    token.transfer(&user1, &user2, &100);
+    assert_eq!(token.balance(&user1), 400);
+
+    // Tüm hesap dondurma, kısmi dondurmadan bağımsız olarak uygulanmaya devam eder.
+    token.unfreeze_amount(&user1, &150);
+    assert_eq!(token.frozen_amount(&user1), 250);
+    token.freeze_account(&user1, &1, &None);
+    assert!(token.try_transfer(&user1, &user2, &150).is_err());
+    token.unfreeze_account(&user1);
+    token.transfer(&user1, &user2, &150);
+
+    assert!(token.try_unfreeze_amount(&user1, &251).is_err());
+    token.unfreeze_amount(&user1, &250);
+    assert_eq!(token.frozen_amount(&user1), 0);
+    token.transfer(&user1, &user2, &250);
+    assert_eq!(token.balance(&user1), 0);
+}
+
+#[test] // Yaptırım listesi güncellemeleri için toplu dondurma ve çözmeyi, varsayılan kaynak bütçesi içinde test eder.
+fn test_batch_freeze() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let mut accounts = Vec::new(&e);
+    for _ in 0..50 {
+        accounts.push_back(Address::generate(&e));
+    }
+    let already_frozen = accounts.get(0).unwrap();
+    token.freeze_account(&already_frozen, &1, &None);
+
+    // Toplu çağrı varsayılan bütçeyle çalışmalıdır; bütçe aşılırsa çağrı başarısız olur.
+    e.cost_estimate().budget().reset_default();
+    token.freeze_accounts(&accounts, &7, &None);
+    assert_eq!(e.events().all().len(), 49); // Zaten dondurulmuş hesap için olay yayınlanmaz.
+    assert_eq!(token.freeze_info(&already_frozen).unwrap().reason, 1);
+    assert_eq!(token.freeze_info(&accounts.get(49).unwrap()).unwrap().reason, 7);
+    assert!(accounts.iter().all(|account| token.is_frozen(&account)));
+
+    token.unfreeze_account(&already_frozen);
+    e.cost_estimate().budget().reset_default();
+    token.unfreeze_accounts(&accounts);
+    assert_eq!(e.events().all().len(), 49); // Zaten çözülmüş hesap için olay yayınlanmaz.
+    assert!(accounts.iter().all(|account| !token.is_frozen(&account)));
+}
+
+#[test] // Yöneticinin zorunlu transferini: dondurulmuş hesaptan taşımayı ve kısmi dondurmanın korunmasını test eder.
+fn test_force_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let lost = Address::generate(&e);
+    let recovered = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&lost, &1000);
+    token.hold(&lost, &operator, &100, &100);
+    token.freeze_amount(&lost, &600);
+    token.freeze_account(&lost, &3, &None);
+    assert!(token.try_transfer(&lost, &recovered, &100).is_err());
+
+    // Rezerve edilmiş bakiye taşınamaz.
+    assert!(token.try_force_transfer(&lost, &recovered, &901, &9).is_err());
+
+    token.force_transfer(&lost, &recovered, &900, &9);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "force_transfer"),
+                    (&lost, &recovered, 900_i128, 9_u32).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        ("force_transfer", admin.clone(), lost.clone(), recovered.clone()).into_val(&e)
+    );
+    let data: (i128, u32) = data.into_val(&e);
+    assert_eq!(data, (900, 9));
+
+    // Kalan bakiye yalnızca rezervasyonu karşılar; dondurulmuş miktar yeni hesaba taşınmıştır.
+    assert_eq!(token.balance(&lost), 100);
+    assert_eq!(token.balance(&recovered), 900);
+    assert_eq!(token.frozen_amount(&lost), 0);
+    assert_eq!(token.frozen_amount(&recovered), 600);
+    assert!(token.try_transfer(&recovered, &lost, &301).is_err());
+    token.transfer(&recovered, &operator, &300);
+}
+
+#[test] // Koruyucu onaylarıyla sosyal kurtarmayı: öneri, onay, bekleme süresi, iptal ve yürütmeyi test eder.
+fn test_social_recovery() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let outsider = Address::generate(&e);
+    let guardian1 = Address::generate(&e);
+    let guardian2 = Address::generate(&e);
+    let guardian3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&owner, &1000);
+    token.approve(&owner, &spender, &300, &200);
+    token.freeze_amount(&owner, &100);
+
+    let mut guardians = Vec::new(&e);
+    guardians.push_back(guardian1.clone());
+    guardians.push_back(guardian2.clone());
+    guardians.push_back(guardian3.clone());
+    assert!(token.try_set_guardians(&owner, &guardians, &4, &10).is_err());
+    token.set_guardians(&owner, &guardians, &2, &10);
+    assert_eq!(token.guardians(&owner).unwrap().threshold, 2);
+
+    // Yalnızca koruyucular kurtarma önerebilir; eşiğe ulaşılmadan kurtarma yürütülemez.
+    assert!(token.try_propose_recovery(&outsider, &owner, &outsider).is_err());
+    token.propose_recovery(&guardian1, &owner, &new_owner);
+    assert!(token.try_approve_recovery(&guardian1, &owner).is_err());
+    assert!(token.try_execute_recovery(&owner).is_err());
+
+    // Sahip, bekleme süresi içinde kurtarmayı iptal edebilir.
+    token.approve_recovery(&guardian2, &owner);
+    assert_eq!(token.pending_recovery(&owner).unwrap().executable_at_ledger, Some(10));
+    token.cancel_recovery(&owner);
+    assert_eq!(token.pending_recovery(&owner), None);
+
+    token.propose_recovery(&guardian2, &owner, &new_owner);
+    token.approve_recovery(&guardian3, &owner);
+    assert!(token.try_execute_recovery(&owner).is_err());
+
+    e.ledger().set_sequence_number(10);
+    token.execute_recovery(&owner);
+    assert_eq!(token.pending_recovery(&owner), None);
+
+    // Bakiye, dondurulmuş miktar ve harcama izni yeni adrese taşınmıştır.
+    assert_eq!(token.balance(&owner), 0);
+    assert_eq!(token.balance(&new_owner), 1000);
+    assert_eq!(token.frozen_amount(&new_owner), 100);
+    assert_eq!(token.allowance(&owner, &spender), 0);
+    assert_eq!(token.allowance(&new_owner, &spender), 300);
+    token.transfer_from(&spender, &new_owner, &outsider, &300);
+    assert_eq!(token.balance(&outsider), 300);
+}
+
+#[test] // İlk sürümün depolama düzeninden sayfalı ve sürdürülebilir geçişi ve sürümlemeyi test eder.
+fn test_version_and_migrate() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // Yeni kurulan kontrat güncel sürümdedir; geçiş yapılacak bir şey yoktur.
+    assert_eq!(token.version(), CONTRACT_VERSION);
+    assert!(token.try_migrate(&Vec::new(&e)).is_err());
+    assert!(token.try_finish_migration().is_err());
+
+    // İlk sürümün depolama düzenini yaz: sürüm, sahip kaydı ve toplam pay yoktur; bakiyeler 'persistent'
+    // depolamada 'i128', dondurma kayıtları ise 'instance' depolamada 'bool' olarak tutulur.
+    e.as_contract(&token.address, || {
+        e.storage().instance().remove(&DataKey::Version);
+        e.storage().instance().remove(&DataKey::TotalShares);
+        e.storage().persistent().set(&DataKey::Balance(user1.clone()), &1000_i128);
+        e.storage().persistent().set(&DataKey::Balance(user2.clone()), &500_i128);
+        e.storage().instance().set(&DataKey::Frozen(user1.clone()), &true);
+    });
+    assert_eq!(token.version(), 1);
+    assert_eq!(token.balance(&user1), 1000);
+    assert!(token.is_frozen(&user1)); // Taşınmamış eski kayıt da dikkate alınır.
+    assert_eq!(token.holder_count(), 0);
+
+    // Geçiş bitmeden ödül dağıtılamaz.
+    token.add_reward_token(&token.address);
+    assert!(token.try_distribute(&user2, &token.address, &10).is_err());
+
+    // Geçiş sırasında bakiyesi değişen hesap kendiliğinden kayda eklenir ve payı toplama dahil edilir.
+    token.transfer(&user2, &user3, &100);
+    assert_eq!(token.total_shares(), 500);
+
+    // Geçiş sayfalar hâlinde yapılır; aynı hesabın tekrar verilmesi bir şey değiştirmez.
+    token.migrate(&Vec::from_array(&e, [user1.clone()]));
+    token.migrate(&Vec::from_array(&e, [user1.clone(), user2.clone()]));
+    assert_eq!(token.total_shares(), 1500);
+    assert_eq!(token.holder_count(), 3);
+    assert_eq!(token.version(), 1);
+
+    token.finish_migration();
+    assert_eq!(token.version(), CONTRACT_VERSION);
+    assert!(token.try_migrate(&Vec::from_array(&e, [user1.clone()])).is_err());
+    assert!(token.try_finish_migration().is_err());
+    e.as_contract(&token.address, || {
+        assert!(!e.storage().instance().has(&DataKey::Frozen(user1.clone())));
+        assert!(e.storage().persistent().has(&DataKey::Frozen(user1.clone())));
+    });
+    let info = token.freeze_info(&user1).unwrap();
+    assert_eq!(info.authority, token.address); // Eski 'bool' kayıt süresiz tam dondurma kabul edilir.
+    assert_eq!(info.unfreeze_at_ledger, None);
+    assert!(token.try_transfer(&user1, &user2, &1).is_err());
+    assert_eq!(token.total_shares(), 1500);
+
+    // Yüklenmemiş bir Wasm koduna yükseltme yapılamaz.
+    assert!(token.try_upgrade(&BytesN::from_array(&e, &[0; 32])).is_err());
+}
+
+#[test] // Harcama izni verilen adresler listesinin sıfırlanan ve süresi dolan izinlerden temizlendiğini test eder.
+fn test_spender_list_pruning() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let spenders = || {
+        e.as_contract(&token.address, || {
+            e.storage()
+                .persistent()
+                .get::<_, Vec<Address>>(&DataKey::Spenders(owner.clone()))
+                .map_or(0, |list| list.len())
+        })
+    };
+
+    // Kısa süreli beş izin listeye eklenir; izin güncellemesi ve kısmi harcama listeyi büyütmez.
+    let short: std::vec::Vec<Address> = (0..5).map(|_| Address::generate(&e)).collect();
+    for spender in &short {
+        token.approve(&owner, spender, &100, &10);
+    }
+    token.approve(&owner, &short[0], &50, &10);
+    token.mint(&owner, &100);
+    token.transfer_from(&short[1], &owner, &admin, &10);
+    assert_eq!(spenders(), 5);
+
+    // Sıfırlanan izin hemen çıkarılır.
+    token.approve(&owner, &short[2], &0, &10);
+    assert_eq!(spenders(), 4);
+
+    // Süresi dolan izinler bir sonraki eklemede çıkarılır.
+    e.ledger().set_sequence_number(11);
+    token.approve(&owner, &admin, &100, &100);
+    assert_eq!(spenders(), 1);
+}
+
+#[test] // İlk sürümlerin 'instance' depolamaya 'true' olarak yazdığı dondurma kayıtlarının okunabildiğini test eder.
+fn test_legacy_bool_freeze() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&user1, &1000);
+
+    // İlk sürümün depolama düzeninde dondurulmuş bir hesap.
+    e.as_contract(&token.address, || {
+        e.storage().instance().set(&DataKey::Frozen(user1.clone()), &true);
+    });
+
+    // Eski kayıt süresiz, neden kodu 0 olan tam bir dondurma olarak okunur.
+    assert!(token.is_frozen(&user1));
+    let info = token.freeze_info(&user1).unwrap();
+    assert_eq!(info.reason, 0);
+    assert_eq!(info.unfreeze_at_ledger, None);
+    assert!(token.try_transfer(&user1, &user2, &100).is_err());
+
+    // Çözme işlemi eski kaydı da siler.
+    token.unfreeze_account(&user1);
+    assert!(!token.is_frozen(&user1));
+    token.transfer(&user1, &user2, &100);
+    assert_eq!(token.balance(&user2), 100);
+}
+
+#[test] // Zaman kilitli yönetici işlemlerinin planlanmasını, bekleme süresini, iptalini ve yürütülmesini test eder.
+fn test_timelock() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // Zaman kilidi kapalıyken işlemler doğrudan çağrılabilir.
+    token.set_max_holders(&Some(10));
+    assert!(token.try_set_timelock_delay(&u32::MAX).is_err()); // Bekleme süresinin üst sınırı vardır.
+    token.set_timelock_delay(&100);
+    assert_eq!(token.timelock_delay(), 100);
+
+    // Zaman kilidi açıkken işlemler doğrudan çağrılamaz ve bekleme süresinden önceye planlanamaz.
+    assert!(token.try_set_admin(&new_admin).is_err());
+    assert!(token.try_set_max_holders(&None).is_err());
+    assert!(token.try_set_timelock_delay(&0).is_err());
+    assert!(token.try_grant_role(&Role::IndexOracle, &new_admin).is_err());
+    assert!(token
+        .try_schedule(&AdminOp::SetAdmin(new_admin.clone()), &99)
+        .is_err());
+
+    let set_admin = token.schedule(&AdminOp::SetAdmin(new_admin.clone()), &100);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, ("schedule", admin.clone(), set_admin).into_val(&e));
+    let data: (AdminOp, u32) = data.into_val(&e);
+    assert_eq!(data, (AdminOp::SetAdmin(new_admin.clone()), 100));
+
+    let remove_cap = token.schedule(&AdminOp::SetMaxHolders(None), &150);
+    assert_eq!(token.pending_ops().len(), 2);
+    token.cancel(&remove_cap);
+    assert_eq!(token.pending_ops().len(), 1);
+    assert!(token.try_execute(&remove_cap).is_err());
+    let grant = token.schedule(&AdminOp::GrantRole(Role::IndexOracle, admin.clone()), &100);
+    assert_eq!(token.pending_ops().len(), 2);
+
+    // İşlem, yürütme defterine ulaşılana kadar yürütülemez. Yönetici devredildiğinde eski yöneticinin
+    // planladığı işlemler kuyruktan silinir.
+    e.ledger().set_sequence_number(99);
+    assert!(token.try_execute(&set_admin).is_err());
+    e.ledger().set_sequence_number(100);
+    token.execute(&set_admin);
+    assert_eq!(token.pending_ops().len(), 0);
+    assert_eq!(token.max_holders(), Some(10));
+    assert!(token.try_execute(&set_admin).is_err());
+    assert!(token.try_execute(&grant).is_err());
+    assert!(!token.has_role(&Role::IndexOracle, &admin));
+
+    // Yeni yönetici de zaman kilidine tabidir.
+    let delay_off = token.schedule(&AdminOp::SetTimelockDelay(0), &200);
+    e.ledger().set_sequence_number(200);
+    token.execute(&delay_off);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            new_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "execute"),
+                    (delay_off,).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.timelock_delay(), 0);
+    token.set_max_holders(&None);
+    token.grant_role(&Role::IndexOracle, &admin);
+}
+
+#[test] // Kontrat üzerindeki M-of-N yönetici konseyini: eşzamanlı imzalı işlemleri, önerileri ve devri test eder.
+fn test_admin_council() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let member1 = Address::generate(&e);
+    let member2 = Address::generate(&e);
+    let member3 = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let mut members = Vec::new(&e);
+    members.push_back(member1.clone());
+    members.push_back(member2.clone());
+    members.push_back(member3.clone());
+    assert!(token.try_set_admin_council(&members, &4).is_err());
+    token.set_admin_council(&members, &2);
+    assert_eq!(token.admin_council().unwrap().threshold, 2);
+
+    // Konsey ayarlıyken tek yöneticili giriş noktaları kapalıdır.
+    assert!(token.try_mint(&user, &100).is_err());
+    assert!(token.try_freeze_account(&user, &1, &None).is_err());
+
+    // Eşzamanlı imzalı işlem: en az iki farklı üyenin imzası gerekir.
+    let mut signers = Vec::new(&e);
+    signers.push_back(member1.clone());
+    assert!(token
+        .try_council_execute(&signers, &AdminOp::Mint(user.clone(), 100))
+        .is_err());
+    signers.push_back(member1.clone());
+    assert!(token
+        .try_council_execute(&signers, &AdminOp::Mint(user.clone(), 100))
+        .is_err());
+    signers.set(1, member2.clone());
+    token.council_execute(&signers, &AdminOp::Mint(user.clone(), 100));
+    assert_eq!(e.auths().len(), 2);
+    assert_eq!(e.auths()[0].0, member1);
+    assert_eq!(e.auths()[1].0, member2);
+    assert_eq!(token.balance(&user), 100);
+
+    // Öneri akışı: onaylar farklı zamanlarda toplanır, eşiğe ulaşınca herkes yürütebilir.
+    let id = token.council_propose(&member1, &AdminOp::FreezeAccount(user.clone(), 5, None));
+    assert!(token.try_execute_proposal(&id).is_err());
+    assert!(token.try_council_approve(&member1, &id).is_err());
+    assert!(token.try_council_approve(&admin, &id).is_err());
+    token.council_approve(&member3, &id);
+    token.execute_proposal(&id);
+    assert!(token.is_frozen(&user));
+    assert_eq!(token.freeze_info(&user).unwrap().authority, token.address);
+    assert!(token.try_proposal(&id).is_err());
+
+    // Konsey, yönetimi yeniden tek bir adrese devredebilir.
+    signers.set(0, member3.clone());
+    token.council_execute(&signers, &AdminOp::SetAdmin(admin.clone()));
+    assert_eq!(token.admin_council(), None);
+    token.mint(&user, &50);
+    assert_eq!(token.balance(&user), 150);
+}
+
+#[test] // Yöneticinin kalıcı olarak bırakılmasını: yönetici ve rol işlemlerinin kapanmasını ve transferlerin sürmesini test eder.
+fn test_renounce_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    token.grant_role(&Role::KycOperator, &user2);
+    token.set_timelock_delay(&10);
+    let id = token.schedule(&AdminOp::SetMaxHolders(Some(5)), &(e.ledger().sequence() + 10));
+    assert_eq!(token.admin(), Some(admin.clone()));
+
+    // Zaman kilidi etkinken bırakma da planlanmalıdır; yürütüldüğünde bekleyen diğer işlemler temizlenir.
+    assert!(token.try_renounce_admin().is_err());
+    let renounce_id = token.schedule(&AdminOp::RenounceAdmin, &(e.ledger().sequence() + 10));
+    e.ledger().set_sequence_number(e.ledger().sequence() + 10);
+    token.execute(&renounce_id);
+    let event = e.events().all().last().unwrap();
+    assert_eq!(event.1, ("renounce_admin", admin.clone()).into_val(&e));
+    assert_eq!(token.admin(), None);
+    assert_eq!(token.pending_ops().len(), 0);
+    assert!(token.try_execute(&id).is_err());
+
+    // Yönetici gerektiren tüm işlemler kapalıdır ve kontrat yeniden kurulamaz.
+    assert!(token.try_mint(&user1, &1).is_err());
+    assert!(token.try_freeze_account(&user1, &1, &None).is_err());
+    assert!(token.try_set_admin(&user2).is_err());
+    assert!(token.try_renounce_admin().is_err());
+    assert!(!initialize_succeeds(&e, &token.address, &user2));
+
+    // Rolleri yönetici verdiğinden, bırakıldıktan sonra rol gerektiren işlemler de kapalıdır.
+    assert!(!token.has_role(&Role::KycOperator, &user2));
+    assert!(token.try_allow(&user2, &user1, &None).is_err());
+    assert!(token.try_update_metadata(&user2, &"new".into_val(&e), &"NEW".into_val(&e)).is_err());
+
+    // Transferler çalışmaya devam eder.
+    token.transfer(&user1, &user2, &100);
+    assert_eq!(token.balance(&user1), 900);
+    assert_eq!(token.balance(&user2), 100);
+}
+
+#[test] // Ad ve sembolün güncellenmesini, ondalığın korunmasını ve genişletilmiş meta veri alanlarını test eder.
+fn test_update_metadata() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.update_metadata(&admin, &"Renamed".into_val(&e), &"RNM".into_val(&e));
+    let event = e.events().all().last().unwrap();
+    assert_eq!(event.1, ("metadata_updated", admin.clone()).into_val(&e));
+    assert_eq!(token.name(), "Renamed".into_val(&e));
+    assert_eq!(token.symbol(), "RNM".into_val(&e));
+    assert_eq!(token.decimals(), 7);
+
+    // Meta veri yöneticisi rolü olmayan adresler meta verileri değiştiremez.
+    let icon = Symbol::new(&e, "icon");
+    let url: String = "https://example.com/icon.png".into_val(&e);
+    assert!(token.try_set_metadata_field(&user, &icon, &url).is_err());
+    token.grant_role(&Role::MetadataManager, &manager);
+    token.set_metadata_field(&manager, &icon, &url);
+    assert_eq!(token.metadata_field(&icon), Some(url.clone()));
+    assert_eq!(token.metadata_field(&Symbol::new(&e, "website")), None);
+
+    // Boş bir değer alanı siler.
+    token.set_metadata_field(&manager, &icon, &"".into_val(&e));
+    assert_eq!(token.metadata_field(&icon), None);
+
+    // Bilinmeyen anahtarlar ve çok uzun değerler tipli hatalarla reddedilir.
+    assert_eq!(
+        token.try_set_metadata_field(&manager, &Symbol::new(&e, "junk"), &url).err().unwrap().unwrap(),
+        MetadataError::InvalidFieldKey.into()
+    );
+    let long = String::from_str(&e, &"a".repeat(257));
+    assert_eq!(
+        token.try_set_metadata_field(&manager, &icon, &long).err().unwrap().unwrap(),
+        MetadataError::FieldValueTooLong.into()
+    );
+    token.set_metadata_field(&manager, &icon, &String::from_str(&e, &"a".repeat(256)));
+
+    // Alanlar 'persistent' depolamada tutulur.
+    e.as_contract(&token.address, || {
+        assert!(e.storage().persistent().has(&DataKey::MetadataField(icon.clone())));
+        assert!(!e.storage().instance().has(&DataKey::MetadataField(icon.clone())));
+    });
+}
+
+#[test] // Ad ve sembol doğrulamasının sınır durumlarını ve tipli hataları test eder.
+fn test_name_symbol_validation() { // Kurucu ve 'update_metadata' aynı 'check_name_symbol' kurallarını kullanır.
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let update = |name: &str, symbol: &str| {
+        token
+            .try_update_metadata(&admin, &name.into_val(&e), &symbol.into_val(&e))
+            .map(|_| ())
+            .map_err(|err| err.unwrap())
+    };
+    let long_name = "n".repeat(64);
+    let too_long_name = "n".repeat(65);
+
+    // Geçerli sınır değerleri.
+    assert_eq!(update("A", "A"), Ok(()));
+    assert_eq!(update(&long_name, "ABCDEFGHIJ12"), Ok(()));
+    assert_eq!(update("My Token (v2) - Üç", "usdc"), Ok(()));
+
+    // Ad: boş, çok uzun veya kontrol karakterli.
+    assert_eq!(update("", "TKN"), Err(MetadataError::EmptyName.into()));
+    assert_eq!(update(&too_long_name, "TKN"), Err(MetadataError::NameTooLong.into()));
+    assert_eq!(update("line\nbreak", "TKN"), Err(MetadataError::InvalidNameCharacter.into()));
+    assert_eq!(update("tab\tname", "TKN"), Err(MetadataError::InvalidNameCharacter.into()));
+    assert_eq!(update("nul\0", "TKN"), Err(MetadataError::InvalidNameCharacter.into()));
+    assert_eq!(update("del\x7f", "TKN"), Err(MetadataError::InvalidNameCharacter.into()));
+
+    // Sembol: boş, çok uzun, boşluklu, noktalama veya ASCII dışı karakterli.
+    assert_eq!(update("Token", ""), Err(MetadataError::InvalidSymbolLength.into()));
+    assert_eq!(update("Token", "ABCDEFGHIJ123"), Err(MetadataError::InvalidSymbolLength.into()));
+    assert_eq!(update("Token", &"S".repeat(1000)), Err(MetadataError::InvalidSymbolLength.into()));
+    assert_eq!(update("Token", "TK N"), Err(MetadataError::InvalidSymbolCharacter.into()));
+    assert_eq!(update("Token", " TKN"), Err(MetadataError::InvalidSymbolCharacter.into()));
+    assert_eq!(update("Token", "TK-N"), Err(MetadataError::InvalidSymbolCharacter.into()));
+    assert_eq!(update("Token", "TKN\n"), Err(MetadataError::InvalidSymbolCharacter.into()));
+    assert_eq!(update("Token", "€UR"), Err(MetadataError::InvalidSymbolCharacter.into()));
+
+    // Reddedilen güncellemeler son geçerli değerleri değiştirmez.
+    assert_eq!(token.name(), "My Token (v2) - Üç".into_val(&e));
+    assert_eq!(token.symbol(), "usdc".into_val(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")] // Beklenen tipli hata: 'MetadataError::EmptyName'.
+fn constructor_rejects_empty_name() { // Boş adla dağıtımın tipli bir hatayla reddedilmesini test eder.
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    e.register(
+        Token,
+        (
+            admin.clone(),
+            7_u32,
+            String::from_str(&e, ""),
+            String::from_str(&e, "TKN"),
+            AccessMode::Open,
+            None::<Address>,
+        ),
+    );
+}
+
+#[test] // Sarmalayıcı modunda alttaki varlığın 1:1 yatırılıp çekilmesini ve arzın karşılıkla eşit kalmasını test eder.
+fn test_wrapped_token() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let asset = e.register_stellar_asset_contract_v2(admin.clone());
+    let underlying = soroban_sdk::token::TokenClient::new(&e, &asset.address());
+    soroban_sdk::token::StellarAssetClient::new(&e, &asset.address()).mint(&user1, &1000);
+
+    let token = TokenClient::new(
+        &e,
+        &e.register(
+            Token,
+            (
+                admin.clone(),
+                7_u32,
+                String::from_str(&e, "Wrapped"),
+                String::from_str(&e, "wXLM"),
+                AccessMode::Open,
+                Some(asset.address()),
+            ),
+        ),
+    );
+    assert_eq!(token.underlying(), Some(asset.address()));
+    let users = [user1.clone(), user2.clone()];
+    let check_backing = || {
+        let supply: i128 = users.iter().map(|user| token.balance(user)).sum();
+        assert_eq!(supply, underlying.balance(&token.address));
+    };
+
+    token.deposit(&user1, &600);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("mint"), token.address.clone(), user1.clone()).into_val(&e));
+    let data: i128 = data.into_val(&e);
+    assert_eq!(data, 600);
+    assert_eq!(token.balance(&user1), 600);
+    assert_eq!(underlying.balance(&user1), 400);
+    check_backing();
+
+    // Sarılmış token serbestçe transfer edilir; çeken taraf alttaki varlığı alır.
+    token.transfer(&user1, &user2, &200);
+    token.withdraw(&user2, &150);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("burn"), user2.clone()).into_val(&e));
+    let data: i128 = data.into_val(&e);
+    assert_eq!(data, 150);
+    assert_eq!(token.balance(&user2), 50);
+    assert_eq!(underlying.balance(&user2), 150);
+    check_backing();
+
+    // Dondurma ve kısmi dondurma sarılmış token'ın üzerine uygulanır.
+    token.freeze_amount(&user1, &300);
+    assert!(token.try_withdraw(&user1, &101).is_err());
+    token.withdraw(&user1, &100);
+    token.freeze_account(&user2, &1, &None);
+    assert!(token.try_withdraw(&user2, &50).is_err());
+    check_backing();
+
+    // Yönetici basımı 1:1 karşılığı bozacağı için kapalıdır; yetersiz alttaki bakiye de yatırılamaz.
+    assert!(token.try_mint(&user1, &1).is_err());
+    assert!(token.try_deposit(&user1, &1000).is_err());
+    check_backing();
+
+    // Yakma da alttaki varlığı serbest bırakmadan arzı azaltacağı için kapalıdır; çekim 'withdraw' ile yapılır.
+    assert!(token.try_burn(&user1, &50).is_err());
+    token.approve(&user1, &user2, &50, &200);
+    assert!(token.try_burn_from(&user2, &user1, &50).is_err());
+    check_backing();
+    assert_eq!(token.balance(&user1), 300);
+    assert_eq!(underlying.balance(&token.address), 350);
+
+    // Normal token'da sarmalayıcı işlemleri kapalıdır.
+    let plain = create_token(&e, &admin);
+    assert_eq!(plain.underlying(), None);
+    assert!(plain.try_deposit(&user1, &1).is_err());
+
+    // Anlık kredi de karşılıksız basım yapacağı için sarmalayıcı modunda kapalıdır.
+    token.set_flash_lender(&Some(admin.clone()));
+    token.set_flash_loan_config(&0, &1000);
+    assert!(token.try_flash_mint(&user1, &100).is_err());
+    check_backing();
+
+    // Endeks değişikliği de bakiyeleri ölçekleyeceği için sarmalayıcı modunda kapalıdır.
+    assert!(token.try_set_index(&admin, &2_000_000_000).is_err());
+    check_backing();
+
+    // Alttaki token ödül token'ı olarak kaydedilemez.
+    assert!(token.try_add_reward_token(&asset.address()).is_err());
+}
+
+#[test]
+#[should_panic(expected = "decimals must match the underlying token")] // Beklenen panik mesajı.
+fn wrapper_decimals_mismatch() { // Alttaki token'dan farklı ondalık sayısıyla sarmalayıcı dağıtmanın paniklemesini test eder.
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let asset = e.register_stellar_asset_contract_v2(admin.clone());
+    e.register(
+        Token,
+        (
+            admin,
+            6_u32,
+            String::from_str(&e, "Wrapped"),
+            String::from_str(&e, "wXLM"),
+            AccessMode::Open,
+            Some(asset.address()),
+        ),
+    );
+}
+
+// 'FlashMarket', anlık kredi testlerinde alıcının ödünç aldığı token'ları gönderip geri aldığı örnek kontrattır.
+// Gördüğü son bakiyeyi kaydeder ve aldığı token'ları geri gönderir.
+#[soroban_sdk::contract]
+pub struct FlashMarket;
+
+#[soroban_sdk::contractimpl]
+impl FlashMarket {
+    pub fn give_back(e: Env, token: Address, to: Address, amount: i128) {
+        let client = TokenClient::new(&e, &token);
+        let balance = client.balance(&e.current_contract_address());
+        e.storage().instance().set(&symbol_short!("seen"), &balance);
+        client.transfer(&e.current_contract_address(), &to, &amount);
+    }
+
+    pub fn seen(e: Env) -> Option<i128> {
+        e.storage().instance().get(&symbol_short!("seen"))
+    }
+}
+
+// 'FlashReceiver', anlık kredi testlerinde kullanılan örnek alıcı kontrattır.
+// Aldığı son krediyi kaydeder; boş veriyle gelen krediyi ödünç aldığı token'ları 'FlashMarket' kontratına gönderip
+// geri alarak kullanır ve kabul eder, veri doluysa reddeder.
+#[soroban_sdk::contract]
+pub struct FlashReceiver;
+
+#[soroban_sdk::contractimpl]
+impl FlashReceiver {
+    pub fn __constructor(e: Env, token: Address, market: Address) {
+        e.storage().instance().set(&symbol_short!("token"), &token);
+        e.storage().instance().set(&symbol_short!("market"), &market);
+    }
+
+    pub fn on_flash_loan(
+        e: Env,
+        initiator: Address,
+        amount: i128,
+        fee: i128,
+        data: soroban_sdk::Bytes,
+    ) -> bool {
+        if !data.is_empty() {
+            return false;
+        }
+        e.storage()
+            .instance()
+            .set(&symbol_short!("last"), &(initiator, amount, fee));
+
+        // Token çağrı yığınında olmadığından alıcı ödünç aldığı token'ları serbestçe kullanabilir.
+        let token: Address = e.storage().instance().get(&symbol_short!("token")).unwrap();
+        let market: Address = e.storage().instance().get(&symbol_short!("market")).unwrap();
+        TokenClient::new(&e, &token).transfer(&e.current_contract_address(), &market, &amount);
+        FlashMarketClient::new(&e, &market).give_back(&token, &e.current_contract_address(), &amount);
+        true
+    }
+
+    pub fn last(e: Env) -> Option<(Address, i128, i128)> {
+        e.storage().instance().get(&symbol_short!("last"))
+    }
+}
+
+#[test] // Kredi kontratı üzerinden anlık kredinin basılmasını, alıcının token'ları kullanmasını, ücretiyle harcama izniyle
+        // geri çekilmesini, sınırları ve geri alınan başarısız kredileri test eder.
+fn test_flash_loan() {
+    use soroban_flash_lender::{FlashLender, FlashLenderClient};
+    use soroban_sdk::{TryFromVal, Val};
+
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let initiator = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let lender = FlashLenderClient::new(&e, &e.register(FlashLender, (token.address.clone(),)));
+    let market = FlashMarketClient::new(&e, &e.register(FlashMarket, ()));
+    let receiver = FlashReceiverClient::new(
+        &e,
+        &e.register(FlashReceiver, (token.address.clone(), market.address.clone())),
+    );
+    let empty = soroban_sdk::Bytes::new(&e);
+    token.mint(&receiver.address, &100);
+
+    // Yönetici bir kredi kontratı ve sınır ayarlayana kadar anlık krediler kapalıdır.
+    assert_eq!(token.flash_lender(), None);
+    assert!(lender.try_flash_loan(&initiator, &receiver.address, &1, &empty).is_err());
+    token.set_flash_lender(&Some(lender.address.clone()));
+    assert_eq!(token.flash_lender(), Some(lender.address.clone()));
+    assert_eq!(token.max_flash_loan(), 0);
+    assert!(lender.try_flash_loan(&initiator, &receiver.address, &1, &empty).is_err());
+    assert!(token.try_set_flash_loan_config(&10_001, &1_000_000).is_err());
+    token.set_flash_loan_config(&50, &1_000_000);
+    assert_eq!(token.flash_fee(&10_000), (50, 50));
+
+    // Alıcı kredi kontratına harcama izni vermeden geri ödeme çekilemez.
+    assert!(lender.try_flash_loan(&initiator, &receiver.address, &10_000, &empty).is_err());
+
+    // Hazine yoksa ücret yakılır. Alıcı krediyi başka bir kontrata gönderip geri alarak kullanır.
+    token.approve(&receiver.address, &lender.address, &10_050, &1000);
+    lender.flash_loan(&initiator, &receiver.address, &10_000, &empty);
+    let events = e.events().all();
+    assert_eq!(receiver.last(), Some((initiator.clone(), 10_000, 50)));
+    assert_eq!(market.seen(), Some(10_000));
+    assert_eq!(token.balance(&market.address), 0);
+    assert_eq!(token.balance(&receiver.address), 50);
+    assert_eq!(token.allowance(&receiver.address, &lender.address), 0);
+    let has_event = |events: &Vec<(Address, Vec<Val>, Val)>, topics: Vec<Val>, amount: i128| {
+        events
+            .iter()
+            .any(|(_, t, data)| t == topics && i128::try_from_val(&e, &data) == Ok(amount))
+    };
+    let mint_topics = (symbol_short!("mint"), lender.address.clone(), receiver.address.clone()).into_val(&e);
+    let burn_topics: Vec<Val> = (symbol_short!("burn"), receiver.address.clone()).into_val(&e);
+    assert!(has_event(&events, mint_topics, 10_000));
+    assert!(has_event(&events, burn_topics.clone(), 10_000));
+    assert!(has_event(&events, burn_topics.clone(), 50)); // Yakılan ücret.
+
+    // Hazine ayarlıysa ücret hazineye aktarılır.
+    token.set_treasury(&treasury);
+    token.approve(&receiver.address, &lender.address, &2_010, &1000);
+    lender.flash_loan(&initiator, &receiver.address, &2_000, &empty);
+    let events = e.events().all();
+    assert!(events
+        .iter()
+        .any(|event| event.1 == ("flash_loan", lender.address.clone(), receiver.address.clone()).into_val(&e)));
+    assert_eq!(token.balance(&receiver.address), 40);
+    assert_eq!(token.balance(&treasury), 10);
+    let transfer_topics = (symbol_short!("transfer"), receiver.address.clone(), treasury.clone()).into_val(&e);
+    assert!(has_event(&events, transfer_topics, 10));
+    assert!(has_event(&events, burn_topics, 2_000));
+
+    // Sınırı aşan, ücreti ödenemeyen veya alıcının reddettiği krediler tamamen geri alınır.
+    token.approve(&receiver.address, &lender.address, &1_000_000, &1000);
+    assert!(lender.try_flash_loan(&initiator, &receiver.address, &1_000_001, &empty).is_err());
+    assert!(lender.try_flash_loan(&initiator, &receiver.address, &10_000, &empty).is_err());
+    let reject = soroban_sdk::Bytes::from_array(&e, &[1]);
+    assert!(lender.try_flash_loan(&initiator, &receiver.address, &100, &reject).is_err());
+    assert_eq!(token.balance(&receiver.address), 40);
+    assert_eq!(receiver.last(), Some((initiator, 2_000, 10)));
+
+    // Açık kredi olmadan geri ödeme çekilemez; kredi kontratının yetkisi olmadan kredi basılamaz.
+    assert!(token.try_flash_settle(&receiver.address).is_err());
+    e.set_auths(&[]);
+    assert!(token.try_flash_mint(&receiver.address, &1).is_err());
+    assert_eq!(token.balance(&receiver.address), 40);
+
+    // Kayıtlı kontrat basım yapabildiği için yeni kredi kontratı zaman kilidine tabidir; kapatmak hemen yapılabilir.
+    e.mock_all_auths();
+    token.set_timelock_delay(&100);
+    assert!(token.try_set_flash_lender(&Some(treasury)).is_err());
+    token.set_flash_lender(&None);
+    assert_eq!(token.flash_lender(), None);
+}
+
+#[test] // Pay tabanlı muhasebede bakiyelerin endeksle büyümesini, yuvarlama kurallarını ve pay görünümlerini test eder.
+fn test_rebasing_shares() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let oracle = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // Endeks 1.0 iken pay ve bakiye aynıdır.
+    assert_eq!(token.index(), 1_000_000_000);
+    token.mint(&user1, &1000);
+    assert_eq!(token.shares_of(&user1), 1000);
+    assert_eq!(token.total_shares(), 1000);
+
+    // Endeks oracle'ı faizi endekse işler; bakiyeler payı değiştirmeden büyür.
+    assert!(token.try_set_index(&oracle, &1_100_000_000).is_err());
+    token.grant_role(&Role::IndexOracle, &oracle);
+    assert!(token.try_set_index(&oracle, &0).is_err());
+    token.set_index(&oracle, &1_100_000_000);
+    assert_eq!(token.balance(&user1), 1100);
+    assert_eq!(token.shares_of(&user1), 1000);
+
+    // Alınan miktar paya aşağı, harcanan miktar yukarı yuvarlanır: 100 / 1.1 = 90.9 pay.
+    token.transfer(&user1, &user2, &100);
+    assert_eq!(token.shares_of(&user1), 909);
+    assert_eq!(token.shares_of(&user2), 90);
+    assert_eq!(token.balance(&user1), 999);
+    assert_eq!(token.balance(&user2), 99);
+    assert_eq!(token.total_shares(), 999);
+
+    // Basım ve yakma da aynı kuralları izler; bakiyenin tamamı her zaman harcanabilir.
+    token.mint(&user2, &11);
+    assert_eq!(token.shares_of(&user2), 100);
+    assert_eq!(token.balance(&user2), 110);
+    token.burn(&user2, &110);
+    assert_eq!(token.shares_of(&user2), 0);
+    assert!(token.try_transfer(&user1, &user2, &1000).is_err());
+    token.transfer(&user1, &user2, &999);
+    assert_eq!(token.shares_of(&user1), 0);
+    assert_eq!(token.total_shares(), token.shares_of(&user2));
+
+    // Endeks düşürülebilir (negatif faiz); bakiyeler aynı oranda küçülür.
+    token.set_index(&admin, &500_000_000);
+    assert_eq!(token.balance(&user2), token.shares_of(&user2) / 2);
+
+    // Yönetici bırakıldıktan sonra endeks oracle'ı da endeksi değiştiremez.
+    token.renounce_admin();
+    assert!(token.try_set_index(&oracle, &1_000_000_000).is_err());
+    assert_eq!(token.index(), 500_000_000);
+}
+
+#[test] // Ödül token'larının kaydını ve kayıttan çıkarılmasını, orantılı dağıtımı ve ödüllerin talep edilmesini test eder.
+fn test_reward_distribution() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let funder = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let asset = e.register_stellar_asset_contract_v2(admin.clone());
+    let reward = soroban_sdk::token::TokenClient::new(&e, &asset.address());
+    soroban_sdk::token::StellarAssetClient::new(&e, &asset.address()).mint(&funder, &1000);
+
+    // Kayıtlı olmayan token'la dağıtım yapılamaz; token'ları yalnızca yönetici kaydeder.
+    assert!(token.try_distribute(&funder, &asset.address(), &100).is_err());
+    e.set_auths(&[]);
+    assert!(token.try_add_reward_token(&asset.address()).is_err());
+    e.mock_all_auths();
+    token.add_reward_token(&asset.address());
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, ("add_reward_token", admin.clone()).into_val(&e));
+    let data: Address = data.into_val(&e);
+    assert_eq!(data, asset.address());
+    assert!(token.try_add_reward_token(&asset.address()).is_err());
+
+    // Sahip yokken dağıtım yapılamaz.
+    assert!(token.try_distribute(&funder, &asset.address(), &100).is_err());
+
+    token.mint(&user1, &300);
+    token.mint(&user2, &100);
+
+    // Sıfır veya negatif miktarla dağıtım yapılamaz.
+    assert!(token.try_distribute(&funder, &asset.address(), &0).is_err());
+    assert!(token.try_distribute(&funder, &asset.address(), &-1).is_err());
+
+    // Başka bir token'la yapılan dağıtım paylarla orantılı paylaşılır.
+    token.distribute(&funder, &asset.address(), &400);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, ("distribute", funder.clone(), asset.address()).into_val(&e));
+    let data: i128 = data.into_val(&e);
+    assert_eq!(data, 400);
+    assert_eq!(reward.balance(&token.address), 400);
+    assert_eq!(token.pending_rewards(&user1, &asset.address()), 300);
+    assert_eq!(token.pending_rewards(&user2, &asset.address()), 100);
+    assert_eq!(token.reward_tokens(), Vec::from_array(&e, [asset.address()]));
+
+    // Transferden sonra kazanılmış ödül korunur; yeni dağıtım yeni paylara göre paylaşılır.
+    token.transfer(&user1, &user2, &200);
+    token.distribute(&funder, &asset.address(), &400);
+    assert_eq!(token.pending_rewards(&user1, &asset.address()), 400);
+    assert_eq!(token.pending_rewards(&user2, &asset.address()), 400);
+
+    assert_eq!(token.claim_rewards(&user1, &asset.address()), 400);
+    assert_eq!(reward.balance(&user1), 400);
+    assert_eq!(token.pending_rewards(&user1, &asset.address()), 0);
+    assert_eq!(token.claim_rewards(&user1, &asset.address()), 0);
+
+    // 'user1' bu dağıtımdan sonra kayıttan çıkarılana kadar hiç güncellenmez.
+    token.distribute(&funder, &asset.address(), &200);
+
+    // Bu token'la yapılan dağıtım kontratta emanette tutulur; emanet ödül kazanmaz.
+    token.mint(&funder, &100);
+    token.add_reward_token(&token.address);
+    token.distribute(&funder, &token.address, &100);
+    assert!(e.events().all().iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("transfer"), funder.clone(), token.address.clone()).into_val(&e)
+    }));
+    assert_eq!(token.balance(&token.address), 100);
+    assert_eq!(token.pending_rewards(&user1, &token.address), 25);
+    assert_eq!(token.pending_rewards(&user2, &token.address), 75);
+    assert_eq!(token.pending_rewards(&token.address, &token.address), 0);
+    assert_eq!(token.claim_rewards(&user2, &token.address), 75);
+    assert!(e.events().all().iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("transfer"), token.address.clone(), user2.clone()).into_val(&e)
+    }));
+    assert_eq!(token.balance(&user2), 375);
+    assert_eq!(token.balance(&token.address), 25);
+
+    // Emanetten ödeme transferle aynı kontrollerden geçer.
+    token.set_required_claims(&soroban_sdk::vec![&e, ClaimTopic::Accredited]);
+    assert!(token.try_claim_rewards(&user1, &token.address).is_err());
+    token.set_required_claims(&Vec::new(&e));
+
+    // Kayıttan çıkarılan token'a dağıtım yapılamaz; daha önce dağıtılmış ödüllerin tamamı, o zamandan beri
+    // güncellenmemiş hesaplar için de talep edilebilir.
+    token.remove_reward_token(&asset.address());
+    let (_, topics, _) = e.events().all().last().unwrap();
+    assert_eq!(topics, ("remove_reward_token", admin.clone()).into_val(&e));
+    assert_eq!(token.reward_tokens(), Vec::from_array(&e, [token.address.clone()]));
+    assert!(token.try_distribute(&funder, &asset.address(), &100).is_err());
+    assert!(token.try_remove_reward_token(&asset.address()).is_err());
+    assert!(token.try_add_reward_token(&asset.address()).is_err()); // Yeniden kaydedilemez.
+    assert_eq!(token.pending_rewards(&user1, &asset.address()), 50);
+    assert_eq!(token.claim_rewards(&user1, &asset.address()), 50);
+    token.transfer(&user2, &user1, &100);
+    assert_eq!(token.pending_rewards(&user1, &asset.address()), 0);
+    assert_eq!(token.pending_rewards(&user2, &asset.address()), 550);
+    assert_eq!(token.claim_rewards(&user2, &asset.address()), 550);
+    assert_eq!(reward.balance(&token.address), 0);
+
+    // Endeks düştüğünde emanetin karşılayamadığı ödül kaybolmaz; hesabın kaydında kalır.
+    token.set_index(&admin, &500_000_000);
+    assert_eq!(token.balance(&token.address), 12);
+    assert_eq!(token.claim_rewards(&user1, &token.address), 12);
+    assert_eq!(token.pending_rewards(&user1, &token.address), 13);
+    assert_eq!(token.claim_rewards(&user1, &token.address), 0);
+
+    // En fazla beş ödül token'ı kaydedilebilir ve en fazla beş token kayıttan çıkarılabilir.
+    let others: std::vec::Vec<Address> = (0..4).map(|_| Address::generate(&e)).collect();
+    for other in others.iter() {
+        token.add_reward_token(other);
+    }
+    assert!(token.try_add_reward_token(&Address::generate(&e)).is_err());
+    for other in others.iter() {
+        token.remove_reward_token(other);
+    }
+    assert!(token.try_remove_reward_token(&token.address).is_err());
+}
+
+#[test] // Merkle airdrop'unun hazineden emanete alınmasını, kanıtla talep edilmesini ve süre dolunca iadesini test eder.
+fn test_merkle_airdrop() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // Beş hesaba 100, 200, ..., 500 token tahsis eden ağaç.
+    let accounts: std::vec::Vec<Address> = (0..5).map(|_| Address::generate(&e)).collect();
+    let leaves: std::vec::Vec<BytesN<32>> = accounts
+        .iter()
+        .enumerate()
+        .map(|(i, account)| airdrop_leaf(&e, i as u32, account, 100 * (i as i128 + 1)))
+        .collect();
+    let (root, proofs) = build_merkle_tree(&e, &leaves);
+    let proof = |index: u32| MerkleProof { index, siblings: proofs[index as usize].clone() };
+
+    assert!(token.try_create_airdrop(&root, &-1, &100).is_err());
+    assert!(token.try_create_airdrop(&root, &1500, &100).is_err()); // Hazine ayarlanmamış.
+    token.set_treasury(&treasury);
+    token.mint(&treasury, &1000);
+    assert!(token.try_create_airdrop(&root, &1500, &100).is_err()); // Hazinenin bakiyesi yetersiz.
+    // Toplam miktar hazineden kontratın emanetine aktarılır.
+    token.mint(&treasury, &500);
+    assert_eq!(token.create_airdrop(&root, &1500, &100), 0);
+    let invocation = AuthorizedInvocation {
+        function: AuthorizedFunction::Contract((
+            token.address.clone(),
+            Symbol::new(&e, "create_airdrop"),
+            (root.clone(), 1500_i128, 100_u32).into_val(&e),
+        )),
+        sub_invocations: std::vec![],
+    };
+    // Hem yönetici hem hazine imzalamalıdır.
+    assert_eq!(
+        e.auths(),
+        std::vec![(admin.clone(), invocation.clone()), (treasury.clone(), invocation)]
+    );
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, ("create_airdrop", admin.clone()).into_val(&e));
+    let data: (u32, BytesN<32>, i128, u32) = data.into_val(&e);
+    assert_eq!(data, (0, root.clone(), 1500, 100));
+    assert!(e.events().all().iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("transfer"), treasury.clone(), token.address.clone()).into_val(&e)
+    }));
+    assert_eq!(token.balance(&treasury), 0);
+    assert_eq!(token.balance(&token.address), 1500);
+
+    // Emanet token miktarı olarak tutulduğu için emanet varken endeks değiştirilemez.
+    assert!(token.try_set_index(&admin, &2_000_000_000).is_err());
+
+    // Alıcı gerekli kimlik beyanlarına sahip olmalıdır.
+    token.set_required_claims(&soroban_sdk::vec![&e, ClaimTopic::Accredited]);
+    assert!(token.try_claim_airdrop(&0, &accounts[0], &100, &proof(0)).is_err());
+    token.set_required_claims(&Vec::new(&e));
+
+    // Geçerli kanıtla talep edilen miktar emanetten ödenir; aynı yaprak ikinci kez talep edilemez.
+    token.claim_airdrop(&0, &accounts[0], &100, &proof(0));
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("transfer"), token.address.clone(), accounts[0].clone()).into_val(&e));
+    let data: i128 = data.into_val(&e);
+    assert_eq!(data, 100);
+    assert_eq!(token.balance(&accounts[0]), 100);
+    assert_eq!(token.balance(&token.address), 1400);
+    assert!(token.is_airdrop_claimed(&0, &0));
+    assert!(!token.is_airdrop_claimed(&0, &1));
+    assert!(token.try_claim_airdrop(&0, &accounts[0], &100, &proof(0)).is_err());
+
+    // Yanlış miktar, başka hesabın kanıtı veya ağacın dışındaki bir sıra reddedilir.
+    assert!(token.try_claim_airdrop(&0, &accounts[1], &300, &proof(1)).is_err());
+    assert!(token.try_claim_airdrop(&0, &accounts[2], &200, &proof(1)).is_err());
+    let mut shifted = proof(1);
+    shifted.index += 8;
+    assert!(token.try_claim_airdrop(&0, &accounts[1], &200, &shifted).is_err());
+
+    // Tek sayıda düğümlü seviyede kendisiyle eşleşen son yaprak da talep edilebilir. Yönetici bırakıldıktan sonra da
+    // talepler yeni token basmadan emanetten ödenir.
+    token.renounce_admin();
+    token.claim_airdrop(&0, &accounts[4], &500, &proof(4));
+    assert_eq!(token.balance(&accounts[4]), 500);
+    assert_eq!(token.airdrop(&0).claimed, 600);
+    assert!(token.try_reclaim_airdrop(&0).is_err()); // Süre dolmadan iade edilemez.
+
+    // Süre dolduktan sonra talep edilemez; kalan miktar hazineye iade edilir.
+    e.ledger().set_sequence_number(101);
+    assert!(token.try_claim_airdrop(&0, &accounts[1], &200, &proof(1)).is_err());
+    assert_eq!(token.reclaim_airdrop(&0), 900);
+    assert!(e.events().all().iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("transfer"), token.address.clone(), treasury.clone()).into_val(&e)
+    }));
+    assert_eq!(token.balance(&treasury), 900);
+    assert_eq!(token.balance(&token.address), 0);
+    assert_eq!(token.balance(&accounts[0]) + token.balance(&accounts[4]) + token.balance(&treasury), 1500);
+    assert_eq!(token.reclaim_airdrop(&0), 0);
+}
@@ -0,0 +1,145 @@
+// Bu dosya (timelock.rs), 'soroban-token-contract' kütüphanesinin 'timelock' modülünü oluşturur.
+// Bu modül, yönetici alt sisteminin zaman kilidi (timelock) kuyruğunu yönetir: yönetici değişikliği, sınır
+// değişiklikleri ve yükseltmeler gibi işlemler ('AdminOp') önce planlanır ve ancak en az bekleme süresi
+// geçtikten sonra yürütülebilir; böylece token sahipleri bu işlemleri önceden görebilir.
+// Bekleme süresi sıfırken zaman kilidi kapalıdır ve bu işlemler doğrudan çağrılabilir.
+// Kuyruk ayarları 'instance' depolamada, planlanmış işlemler ise 'persistent' depolamada saklanır.
+
+use crate::storage_types::{
+    AdminOp, DataKey, ScheduledOp, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD, DAY_IN_LEDGERS,
+};
+use soroban_sdk::{Env, Vec};
+
+// 'MAX_TIMELOCK_DELAY', bekleme süresinin üst sınırıdır (yaklaşık 14 gün). Planlanmış işlemlerin depolama
+// ömrünün içinde kalır; çok büyük bir gecikme tüm zaman kilitli işlemleri kalıcı olarak planlanamaz hale getiremez.
+pub const MAX_TIMELOCK_DELAY: u32 = 14 * DAY_IN_LEDGERS;
+
+// 'read_timelock_delay' fonksiyonu, planlama ile yürütme arasındaki en az defter sayısını okur.
+// Ayarlanmamışsa zaman kilidi kapalıdır (0).
+pub fn read_timelock_delay(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::TimelockDelay)
+        .unwrap_or(0)
+}
+
+// 'write_timelock_delay' fonksiyonu, planlama ile yürütme arasındaki en az defter sayısını yazar.
+// Süre 'MAX_TIMELOCK_DELAY' değerini aşarsa program panikler.
+pub fn write_timelock_delay(e: &Env, delay: u32) {
+    if delay > MAX_TIMELOCK_DELAY {
+        panic!("timelock delay exceeds the maximum");
+    }
+    e.storage().instance().set(&DataKey::TimelockDelay, &delay);
+}
+
+// 'require_no_timelock' fonksiyonu, zaman kilidi etkinse programı paniklendirir.
+// Zaman kilitli işlemlerin doğrudan çağrılan giriş noktaları tarafından kullanılır.
+pub fn require_no_timelock(e: &Env) {
+    if read_timelock_delay(e) > 0 {
+        panic!("operation is timelocked and must be scheduled");
+    }
+}
+
+// 'earliest_eta' fonksiyonu, şimdi planlanan bir işlemin yürütülebileceği en erken defteri döndürür.
+pub fn earliest_eta(e: &Env) -> u32 {
+    e.ledger()
+        .sequence()
+        .checked_add(read_timelock_delay(e))
+        .unwrap_or_else(|| panic!("eta_ledger overflow"))
+}
+
+// 'is_timelocked' fonksiyonu, işlemin zaman kilidine tabi olup olmadığını döndürür. Yönetimi ve kuralları
+// değiştiren işlemler zaman kilitlidir; basım ve dondurma gibi günlük işlemler değildir. Rol atamak da zaman kilitlidir,
+// çünkü endeks oracle'ı gibi roller tüm bakiyeleri etkileyebilir; rolü geri almak ise hemen yapılabilir. Aynı nedenle
+// anlık kredi kontratını ayarlamak zaman kilitlidir (kayıtlı kontrat basım yapabilir), kapatmak ise değildir.
+pub fn is_timelocked(op: &AdminOp) -> bool {
+    matches!(
+        op,
+        AdminOp::SetAdmin(_)
+            | AdminOp::SetMaxHolders(_)
+            | AdminOp::SetMaxBalancePerAccount(_)
+            | AdminOp::SetCompliance(_)
+            | AdminOp::Upgrade(_)
+            | AdminOp::SetTimelockDelay(_)
+            | AdminOp::SetAdminCouncil(_, _)
+            | AdminOp::RenounceAdmin
+            | AdminOp::GrantRole(_, _)
+            | AdminOp::SetFlashLender(Some(_))
+    )
+}
+
+// 'next_op_id' fonksiyonu, yeni planlanan işlem için bir kimlik üretir ve işlem sayacını artırır.
+pub fn next_op_id(e: &Env) -> u32 {
+    let id: u32 = e.storage().instance().get(&DataKey::OpCount).unwrap_or(0);
+    e.storage().instance().set(&DataKey::OpCount, &(id + 1));
+    id
+}
+
+// 'read_pending_op_ids' fonksiyonu, bekleyen planlanmış işlemlerin kimliklerini okur.
+fn read_pending_op_ids(e: &Env) -> Vec<u32> {
+    e.storage()
+        .instance()
+        .get(&DataKey::PendingOps)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+// 'write_pending_op_ids' fonksiyonu, bekleyen planlanmış işlemlerin kimliklerini yazar.
+fn write_pending_op_ids(e: &Env, ids: &Vec<u32>) {
+    e.storage().instance().set(&DataKey::PendingOps, ids);
+}
+
+// 'read_scheduled_op' fonksiyonu, verilen kimliğe sahip planlanmış işlemi okur.
+// İşlem yoksa (yürütülmüş, iptal edilmiş veya hiç planlanmamışsa) program panikler.
+pub fn read_scheduled_op(e: &Env, id: u32) -> ScheduledOp {
+    let key = DataKey::ScheduledOp(id);
+    let op = e
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic!("operation not found"));
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    op
+}
+
+// 'write_scheduled_op' fonksiyonu, yeni planlanan işlemi yazar ve bekleyen işlemler listesine ekler.
+pub fn write_scheduled_op(e: &Env, op: &ScheduledOp) {
+    let key = DataKey::ScheduledOp(op.id);
+    e.storage().persistent().set(&key, op);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+
+    let mut ids = read_pending_op_ids(e);
+    ids.push_back(op.id);
+    write_pending_op_ids(e, &ids);
+}
+
+// 'remove_scheduled_op' fonksiyonu, yürütülen veya iptal edilen işlemi kayıtlardan ve bekleyen işlemler listesinden siler.
+pub fn remove_scheduled_op(e: &Env, id: u32) {
+    e.storage().persistent().remove(&DataKey::ScheduledOp(id));
+    let mut ids = read_pending_op_ids(e);
+    if let Some(index) = ids.first_index_of(id) {
+        ids.remove(index);
+    }
+    write_pending_op_ids(e, &ids);
+}
+
+// 'read_pending_ops' fonksiyonu, bekleyen tüm planlanmış işlemleri planlanma sırasıyla döndürür.
+pub fn read_pending_ops(e: &Env) -> Vec<ScheduledOp> {
+    let mut ops = Vec::new(e);
+    for id in read_pending_op_ids(e).iter() {
+        ops.push_back(read_scheduled_op(e, id));
+    }
+    ops
+}
+
+// 'clear_scheduled_ops' fonksiyonu, bekleyen tüm planlanmış işlemleri siler.
+// Yönetici bırakıldığında veya devredildiğinde kuyrukta eski yönetimin planladığı işlem kalmaması için kullanılır.
+pub fn clear_scheduled_ops(e: &Env) {
+    for id in read_pending_op_ids(e).iter() {
+        e.storage().persistent().remove(&DataKey::ScheduledOp(id));
+    }
+    e.storage().instance().remove(&DataKey::PendingOps);
+}
@@ -0,0 +1,58 @@
+// Bu dosya (upgrade.rs), 'soroban-token-contract' kütüphanesinin 'upgrade' modülünü oluşturur.
+// Bu modül, kontratın depolama düzeninin sürümünü ve sürümler arası depolama geçişlerini (migration) yönetir.
+// Kontrat yeni bir Wasm koduyla yükseltildikten ('upgrade') sonra yönetici 'migrate' fonksiyonunu çağırır;
+// depolanan sürümden 'CONTRACT_VERSION' sürümüne kadar olan geçişler sırayla ve her sürüm için bir kez çalışır.
+// Sürüm bilgisi olmayan (sürümleme eklenmeden önce kurulmuş) kontratlar sürüm 1 kabul edilir.
+
+use crate::balance::register_legacy_holder;
+use crate::freeze::migrate_freeze;
+use crate::storage_types::DataKey;
+use soroban_sdk::{Address, Env, Vec};
+
+// 'CONTRACT_VERSION', bu kodun beklediği depolama düzeninin sürümüdür.
+// Sürüm 2: Dondurma kayıtları ('DataKey::Frozen') 'instance' depolamadan 'persistent' depolamaya taşındı.
+// Sürüm 3: Bakiyeler pay olarak yorumlanır ve toplam pay ('DataKey::TotalShares') ayrıca tutulur.
+pub(crate) const CONTRACT_VERSION: u32 = 3;
+
+// 'read_version' fonksiyonu, depolamadaki sürümü okur. Sürüm yazılmamışsa 1 döndürür.
+pub fn read_version(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Version).unwrap_or(1)
+}
+
+// 'write_version' fonksiyonu, depolama sürümünü yazar.
+pub fn write_version(e: &Env, version: u32) {
+    e.storage().instance().set(&DataKey::Version, &version);
+}
+
+// 'check_migration_pending' fonksiyonu, depolama zaten güncelse program paniğe uğratır.
+fn check_migration_pending(e: &Env) -> u32 {
+    let version = read_version(e);
+    if version >= CONTRACT_VERSION {
+        panic!("storage is already migrated");
+    }
+    version
+}
+
+// 'migrate_accounts' fonksiyonu, verilen hesapların depolamasını güncel düzene taşır ve taşınan hesap sayısını döndürür.
+// Depolama anahtarları zincir üzerinde sayılamadığından (ilk sürümlerde sahip kaydı da yoktur), hesaplar zincir dışında
+// olaylardan derlenip sayfa sayfa verilir. Her adım tekrar çalıştırılabilir; aynı hesap birden çok kez verilebilir ve
+// yarıda kalan geçiş kaldığı yerden sürdürülür:
+// - Sürüm 2: 'instance' depolamadaki eski dondurma kaydı (ilk sürümlerin 'bool' kaydı dahil) 'persistent' depolamaya taşınır.
+// - Sürüm 3: Bakiyesi olup sahip kaydında olmayan hesap kayda eklenir ve payı toplam paya eklenir.
+pub fn migrate_accounts(e: &Env, accounts: &Vec<Address>) -> u32 {
+    check_migration_pending(e);
+    for account in accounts.iter() {
+        migrate_freeze(e, &account);
+        register_legacy_holder(e, &account);
+    }
+    accounts.len()
+}
+
+// 'finish_migration' fonksiyonu, tüm hesaplar taşındıktan sonra yeni sürümü yazar ve eski ve yeni sürümü döndürür.
+// Depolama zaten güncelse program panikler. Geçişte verilmemiş hesapların eski dondurma kayıtları 'read_freeze'
+// tarafından okunmaya devam eder; payları ise hesabın bakiyesi ilk değiştiğinde toplam paya eklenir.
+pub fn finish_migration(e: &Env) -> (u32, u32) {
+    let version = check_migration_pending(e);
+    write_version(e, CONTRACT_VERSION);
+    (version, CONTRACT_VERSION)
+}
@@ -0,0 +1,27 @@
+// Bu dosya (wrapper.rs), 'soroban-token-contract' kütüphanesinin 'wrapper' modülünü oluşturur.
+// Bu modül, token'ın mevcut bir SEP-41 varlığını (örneğin bir Stellar varlık kontratını) 1:1 sardığı sarmalayıcı
+// (wrapper) modunu yönetir. Sarmalayıcı modunda 'deposit' alttaki varlığı kontrata çekip aynı miktarda sarılmış
+// token basar, 'withdraw' ise sarılmış token'ı yakıp alttaki varlığı geri öder. Böylece dondurma, kısmi dondurma
+// ve rezervasyon gibi özellikler alttaki varlığın üzerine eklenebilir.
+
+use crate::storage_types::DataKey;
+use soroban_sdk::{token, Address, Env};
+
+// 'read_underlying' fonksiyonu, sarılan alttaki token'ın adresini (sarmalayıcı modundaysa) okur.
+pub fn read_underlying(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Underlying)
+}
+
+// 'write_underlying' fonksiyonu, sarılan alttaki token'ın adresini yazar.
+// Alttaki token'ın ondalık sayısı, sarılmış token'ınkiyle aynı olmalıdır; aksi halde 1:1 oran anlamını yitirir.
+pub fn write_underlying(e: &Env, underlying: &Address, decimal: u32) {
+    if token::Client::new(e, underlying).decimals() != decimal {
+        panic!("decimals must match the underlying token");
+    }
+    e.storage().instance().set(&DataKey::Underlying, underlying);
+}
+
+// 'require_underlying' fonksiyonu, alttaki token'ın adresini döndürür; token sarmalayıcı modunda değilse panikler.
+pub fn require_underlying(e: &Env) -> Address {
+    read_underlying(e).unwrap_or_else(|| panic!("token is not a wrapper"))
+}